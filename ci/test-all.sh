@@ -6,3 +6,4 @@ set -o errexit -o nounset
 export RUSTFLAGS="--deny warnings"
 
 cargo test --verbose
+cargo test --verbose --all-features
@@ -0,0 +1,1674 @@
+use std::{collections::VecDeque, fmt, iter::FusedIterator};
+
+use crate::{IterStatusExt, Status, WithStatus};
+
+/// Iterator adapter which maps only the first item. See
+/// [`IterStatusExt::map_first`][crate::IterStatusExt::map_first] for more
+/// information.
+#[derive(Clone)]
+pub struct MapFirst<I, F> {
+    iter: I,
+    f: Option<F>,
+}
+
+impl<I, F> MapFirst<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter,
+            f: Some(f),
+        }
+    }
+}
+
+impl<I: fmt::Debug, F> fmt::Debug for MapFirst<I, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MapFirst")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, F> Iterator for MapFirst<I, F>
+where
+    I: Iterator,
+    F: FnOnce(I::Item) -> I::Item,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        match self.f.take() {
+            Some(f) => Some(f(item)),
+            None => Some(item),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> FusedIterator for MapFirst<I, F>
+where
+    I: FusedIterator,
+    F: FnOnce(I::Item) -> I::Item,
+{}
+
+impl<I, F> ExactSizeIterator for MapFirst<I, F>
+where
+    I: ExactSizeIterator,
+    F: FnOnce(I::Item) -> I::Item,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+/// Iterator adapter which maps only the last item. See
+/// [`IterStatusExt::map_last`][crate::IterStatusExt::map_last] for more
+/// information.
+pub struct MapLast<I: Iterator, F> {
+    iter: WithStatus<I>,
+    f: Option<F>,
+}
+
+impl<I: Iterator, F> MapLast<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter: iter.with_status(),
+            f: Some(f),
+        }
+    }
+}
+
+impl<I, F> Clone for MapLast<I, F>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<I, F> fmt::Debug for MapLast<I, F>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MapLast")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, F> Iterator for MapLast<I, F>
+where
+    I: Iterator,
+    F: FnOnce(I::Item) -> I::Item,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (item, status) = self.iter.next()?;
+        Some(map_if_last(&mut self.f, item, status))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> DoubleEndedIterator for MapLast<I, F>
+where
+    I: DoubleEndedIterator + ExactSizeIterator,
+    F: FnOnce(I::Item) -> I::Item,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (item, status) = self.iter.next_back()?;
+        Some(map_if_last(&mut self.f, item, status))
+    }
+}
+
+impl<I, F> FusedIterator for MapLast<I, F>
+where
+    I: FusedIterator,
+    F: FnOnce(I::Item) -> I::Item,
+{}
+
+impl<I, F> ExactSizeIterator for MapLast<I, F>
+where
+    I: ExactSizeIterator,
+    F: FnOnce(I::Item) -> I::Item,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+/// Applies `f` to `item` if it's the last one and `f` wasn't used yet.
+fn map_if_last<T, F>(f: &mut Option<F>, item: T, status: Status) -> T
+where
+    F: FnOnce(T) -> T,
+{
+    if status.is_last() {
+        if let Some(f) = f.take() {
+            return f(item);
+        }
+    }
+
+    item
+}
+
+/// Iterator adapter which maps all items that are neither first nor last. See
+/// [`IterStatusExt::map_in_between`][crate::IterStatusExt::map_in_between]
+/// for more information.
+pub struct MapInBetween<I: Iterator, F> {
+    iter: WithStatus<I>,
+    f: F,
+}
+
+impl<I: Iterator, F> MapInBetween<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter: iter.with_status(),
+            f,
+        }
+    }
+}
+
+impl<I, F> Clone for MapInBetween<I, F>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<I, F> fmt::Debug for MapInBetween<I, F>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MapInBetween")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, F> Iterator for MapInBetween<I, F>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> I::Item,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (item, status) = self.iter.next()?;
+        if status.is_in_between() {
+            Some((self.f)(item))
+        } else {
+            Some(item)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> DoubleEndedIterator for MapInBetween<I, F>
+where
+    I: DoubleEndedIterator + ExactSizeIterator,
+    F: FnMut(I::Item) -> I::Item,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (item, status) = self.iter.next_back()?;
+        if status.is_in_between() {
+            Some((self.f)(item))
+        } else {
+            Some(item)
+        }
+    }
+}
+
+impl<I, F> FusedIterator for MapInBetween<I, F>
+where
+    I: FusedIterator,
+    F: FnMut(I::Item) -> I::Item,
+{}
+
+impl<I, F> ExactSizeIterator for MapInBetween<I, F>
+where
+    I: ExactSizeIterator,
+    F: FnMut(I::Item) -> I::Item,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+/// Iterator adapter which calls a closure right before the first item is
+/// yielded. See
+/// [`IterStatusExt::before_first`][crate::IterStatusExt::before_first] for
+/// more information.
+#[derive(Clone)]
+pub struct BeforeFirst<I, F> {
+    iter: I,
+    f: Option<F>,
+}
+
+impl<I, F> BeforeFirst<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter,
+            f: Some(f),
+        }
+    }
+}
+
+impl<I: fmt::Debug, F> fmt::Debug for BeforeFirst<I, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BeforeFirst")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, F> Iterator for BeforeFirst<I, F>
+where
+    I: Iterator,
+    F: FnOnce(),
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        if let Some(f) = self.f.take() {
+            f();
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> FusedIterator for BeforeFirst<I, F>
+where
+    I: FusedIterator,
+    F: FnOnce(),
+{}
+
+impl<I, F> ExactSizeIterator for BeforeFirst<I, F>
+where
+    I: ExactSizeIterator,
+    F: FnOnce(),
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+/// Iterator adapter which calls a closure right after the last item was
+/// yielded. See
+/// [`IterStatusExt::after_last`][crate::IterStatusExt::after_last] for more
+/// information.
+#[derive(Clone)]
+pub struct AfterLast<I, F> {
+    iter: I,
+    f: Option<F>,
+    started: bool,
+}
+
+impl<I, F> AfterLast<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter,
+            f: Some(f),
+            started: false,
+        }
+    }
+}
+
+impl<I: fmt::Debug, F> fmt::Debug for AfterLast<I, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AfterLast")
+            .field("iter", &self.iter)
+            .field("started", &self.started)
+            .finish()
+    }
+}
+
+impl<I, F> Iterator for AfterLast<I, F>
+where
+    I: Iterator,
+    F: FnOnce(),
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next();
+        match item {
+            Some(_) => self.started = true,
+            None if self.started => {
+                if let Some(f) = self.f.take() {
+                    f();
+                }
+            }
+            None => {}
+        }
+
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> FusedIterator for AfterLast<I, F>
+where
+    I: FusedIterator,
+    F: FnOnce(),
+{}
+
+impl<I, F> ExactSizeIterator for AfterLast<I, F>
+where
+    I: ExactSizeIterator,
+    F: FnOnce(),
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+/// Iterator adapter which calls a closure with a reference to the first item.
+/// See [`IterStatusExt::tap_first`][crate::IterStatusExt::tap_first] for more
+/// information.
+#[derive(Clone)]
+pub struct TapFirst<I, F> {
+    iter: I,
+    f: Option<F>,
+}
+
+impl<I, F> TapFirst<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter,
+            f: Some(f),
+        }
+    }
+}
+
+impl<I: fmt::Debug, F> fmt::Debug for TapFirst<I, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TapFirst")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, F> Iterator for TapFirst<I, F>
+where
+    I: Iterator,
+    F: FnOnce(&I::Item),
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        if let Some(f) = self.f.take() {
+            f(&item);
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> FusedIterator for TapFirst<I, F>
+where
+    I: FusedIterator,
+    F: FnOnce(&I::Item),
+{}
+
+impl<I, F> ExactSizeIterator for TapFirst<I, F>
+where
+    I: ExactSizeIterator,
+    F: FnOnce(&I::Item),
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+/// Iterator adapter which calls a closure with a reference to the last item.
+/// See [`IterStatusExt::tap_last`][crate::IterStatusExt::tap_last] for more
+/// information.
+pub struct TapLast<I: Iterator, F> {
+    iter: WithStatus<I>,
+    f: Option<F>,
+}
+
+impl<I: Iterator, F> TapLast<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter: iter.with_status(),
+            f: Some(f),
+        }
+    }
+}
+
+impl<I, F> Clone for TapLast<I, F>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<I, F> fmt::Debug for TapLast<I, F>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TapLast")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, F> Iterator for TapLast<I, F>
+where
+    I: Iterator,
+    F: FnOnce(&I::Item),
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (item, status) = self.iter.next()?;
+        if status.is_last() {
+            if let Some(f) = self.f.take() {
+                f(&item);
+            }
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> FusedIterator for TapLast<I, F>
+where
+    I: FusedIterator,
+    F: FnOnce(&I::Item),
+{}
+
+impl<I, F> ExactSizeIterator for TapLast<I, F>
+where
+    I: ExactSizeIterator,
+    F: FnOnce(&I::Item),
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+/// Iterator adapter which yields all but the last item. See
+/// [`IterStatusExt::skip_last`][crate::IterStatusExt::skip_last] for more
+/// information.
+pub struct SkipLast<I: Iterator> {
+    iter: I,
+
+    /// The item fetched in advance. It's only yielded once we know it's not
+    /// the last one.
+    peeked: Option<I::Item>,
+}
+
+impl<I: Iterator> SkipLast<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter,
+            peeked: None,
+        }
+    }
+}
+
+impl<I> Clone for SkipLast<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            peeked: self.peeked.clone(),
+        }
+    }
+}
+
+impl<I> fmt::Debug for SkipLast<I>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SkipLast")
+            .field("iter", &self.iter)
+            .field("peeked", &self.peeked)
+            .finish()
+    }
+}
+
+impl<I: Iterator> Iterator for SkipLast<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.peeked.is_none() {
+            self.peeked = Some(self.iter.next()?);
+        }
+
+        let next = self.iter.next()?;
+        self.peeked.replace(next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.peeked.is_some() as usize;
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.saturating_add(buffered).saturating_sub(1),
+            upper.and_then(|upper| upper.checked_add(buffered)).map(|n| n.saturating_sub(1)),
+        )
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for SkipLast<I> {}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for SkipLast<I> {}
+
+/// Iterator adapter which yields all but the last `n` items. See
+/// [`IterStatusExt::skip_last_n`][crate::IterStatusExt::skip_last_n] for more
+/// information.
+pub struct SkipLastN<I: Iterator> {
+    iter: I,
+
+    /// Ring buffer of the items fetched in advance. Once it's filled, it
+    /// always holds exactly `n` items.
+    buffer: VecDeque<I::Item>,
+    n: usize,
+}
+
+impl<I: Iterator> SkipLastN<I> {
+    pub(crate) fn new(iter: I, n: usize) -> Self {
+        Self {
+            iter,
+            buffer: VecDeque::with_capacity(n),
+            n,
+        }
+    }
+}
+
+impl<I> Clone for SkipLastN<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            buffer: self.buffer.clone(),
+            n: self.n,
+        }
+    }
+}
+
+impl<I> fmt::Debug for SkipLastN<I>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SkipLastN")
+            .field("iter", &self.iter)
+            .field("buffer", &self.buffer)
+            .field("n", &self.n)
+            .finish()
+    }
+}
+
+impl<I: Iterator> Iterator for SkipLastN<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.buffer.len() < self.n {
+            self.buffer.push_back(self.iter.next()?);
+        }
+
+        let next = self.iter.next()?;
+        if self.n == 0 {
+            return Some(next);
+        }
+
+        self.buffer.push_back(next);
+        self.buffer.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.buffer.len();
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.saturating_add(buffered).saturating_sub(self.n),
+            upper.and_then(|upper| upper.checked_add(buffered)).map(|n| n.saturating_sub(self.n)),
+        )
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for SkipLastN<I> {}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for SkipLastN<I> {}
+
+/// Iterator adapter which places a separator created by a closure between
+/// every two items. See
+/// [`IterStatusExt::intersperse_with`][crate::IterStatusExt::intersperse_with]
+/// for more information.
+pub struct IntersperseWith<I: Iterator, F> {
+    iter: WithStatus<I>,
+    f: F,
+
+    /// Whether a separator has to be yielded next.
+    sep_next: bool,
+}
+
+impl<I: Iterator, F> IntersperseWith<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter: iter.with_status(),
+            f,
+            sep_next: false,
+        }
+    }
+}
+
+impl<I, F> Clone for IntersperseWith<I, F>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            f: self.f.clone(),
+            sep_next: self.sep_next,
+        }
+    }
+}
+
+impl<I, F> fmt::Debug for IntersperseWith<I, F>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("IntersperseWith")
+            .field("iter", &self.iter)
+            .field("sep_next", &self.sep_next)
+            .finish()
+    }
+}
+
+impl<I, F> Iterator for IntersperseWith<I, F>
+where
+    I: Iterator,
+    F: FnMut() -> I::Item,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.sep_next {
+            self.sep_next = false;
+            return Some((self.f)());
+        }
+
+        let (item, status) = self.iter.next()?;
+        self.sep_next = !status.is_last();
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every remaining item is preceded by a separator, except for the
+        // very first item.
+        let (lower, upper) = self.iter.size_hint();
+        let with_seps = |n: usize| {
+            if self.sep_next {
+                n.checked_mul(2)
+            } else {
+                n.checked_mul(2).map(|n| n.saturating_sub(1))
+            }
+        };
+
+        (
+            with_seps(lower).unwrap_or(usize::MAX),
+            upper.and_then(with_seps),
+        )
+    }
+}
+
+impl<I, F> FusedIterator for IntersperseWith<I, F>
+where
+    I: FusedIterator,
+    F: FnMut() -> I::Item,
+{}
+
+/// Iterator adapter which places a separator between every two items and a
+/// terminator after the last one. See
+/// [`IterStatusExt::terminate_with`][crate::IterStatusExt::terminate_with]
+/// for more information.
+pub struct TerminateWith<I: Iterator> {
+    iter: WithStatus<I>,
+    sep: I::Item,
+    term: I::Item,
+
+    /// The status of the item yielded last, if a separator or terminator has
+    /// to be yielded next.
+    pending: Option<Status>,
+}
+
+impl<I: Iterator> TerminateWith<I> {
+    pub(crate) fn new(iter: I, sep: I::Item, term: I::Item) -> Self {
+        Self {
+            iter: iter.with_status(),
+            sep,
+            term,
+            pending: None,
+        }
+    }
+}
+
+impl<I> Clone for TerminateWith<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            sep: self.sep.clone(),
+            term: self.term.clone(),
+            pending: self.pending,
+        }
+    }
+}
+
+impl<I> fmt::Debug for TerminateWith<I>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TerminateWith")
+            .field("iter", &self.iter)
+            .field("sep", &self.sep)
+            .field("term", &self.term)
+            .field("pending", &self.pending)
+            .finish()
+    }
+}
+
+impl<I> Iterator for TerminateWith<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.pending.take() {
+            Some(status) if status.is_last() => Some(self.term.clone()),
+            Some(_) => Some(self.sep.clone()),
+            None => {
+                let (item, status) = self.iter.next()?;
+                self.pending = Some(status);
+                Some(item)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every remaining item is followed by a separator or terminator.
+        let pending = self.pending.is_some() as usize;
+        let (lower, upper) = self.iter.size_hint();
+        let with_delims = |n: usize| n.checked_mul(2).and_then(|n| n.checked_add(pending));
+
+        (
+            with_delims(lower).unwrap_or(usize::MAX),
+            upper.and_then(with_delims),
+        )
+    }
+}
+
+impl<I> FusedIterator for TerminateWith<I>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+{}
+
+/// Iterator adapter which yields the items enclosed in opening and closing
+/// delimiters and separated by a separator. See
+/// [`IterStatusExt::delimit`][crate::IterStatusExt::delimit] for more
+/// information.
+pub struct Delimit<I: Iterator, D> {
+    iter: WithStatus<I>,
+    open: Option<D>,
+    sep: D,
+    close: Option<D>,
+
+    /// Whether a separator has to be yielded next.
+    sep_next: bool,
+}
+
+impl<I: Iterator, D> Delimit<I, D> {
+    pub(crate) fn new(iter: I, open: D, sep: D, close: D) -> Self {
+        Self {
+            iter: iter.with_status(),
+            open: Some(open),
+            sep,
+            close: Some(close),
+            sep_next: false,
+        }
+    }
+}
+
+impl<I, D> Clone for Delimit<I, D>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    D: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            open: self.open.clone(),
+            sep: self.sep.clone(),
+            close: self.close.clone(),
+            sep_next: self.sep_next,
+        }
+    }
+}
+
+impl<I, D> fmt::Debug for Delimit<I, D>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+    D: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Delimit")
+            .field("iter", &self.iter)
+            .field("open", &self.open)
+            .field("sep", &self.sep)
+            .field("close", &self.close)
+            .field("sep_next", &self.sep_next)
+            .finish()
+    }
+}
+
+impl<I, D> Iterator for Delimit<I, D>
+where
+    I: Iterator,
+    D: Clone,
+{
+    type Item = Token<I::Item, D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(open) = self.open.take() {
+            return Some(Token::Open(open));
+        }
+
+        if self.sep_next {
+            self.sep_next = false;
+            return Some(Token::Sep(self.sep.clone()));
+        }
+
+        match self.iter.next() {
+            Some((item, status)) => {
+                self.sep_next = !status.is_last();
+                Some(Token::Item(item))
+            }
+            None => self.close.take().map(Token::Close),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every remaining item except the first is preceded by a separator.
+        let (lower, upper) = self.iter.size_hint();
+        let extra = self.open.is_some() as usize
+            + self.close.is_some() as usize
+            + self.sep_next as usize;
+        let with_delims = |n: usize| {
+            n.checked_mul(2)
+                .map(|n| n.saturating_sub(1))
+                .and_then(|n| n.checked_add(extra))
+        };
+
+        (
+            with_delims(lower).unwrap_or(usize::MAX),
+            upper.and_then(with_delims),
+        )
+    }
+}
+
+impl<I, D> FusedIterator for Delimit<I, D>
+where
+    I: FusedIterator,
+    D: Clone,
+{}
+
+/// A token yielded by [`Delimit`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Token<T, D> {
+    /// The opening delimiter, yielded first.
+    Open(D),
+
+    /// An item of the underlying iterator.
+    Item(T),
+
+    /// The separator, yielded between every two items.
+    Sep(D),
+
+    /// The closing delimiter, yielded last.
+    Close(D),
+}
+
+/// Iterator adapter which yields a header before the first and a footer after
+/// the last item. See
+/// [`IterStatusExt::surround`][crate::IterStatusExt::surround] for more
+/// information.
+pub struct Surround<I: Iterator> {
+    iter: WithStatus<I>,
+    even_if_empty: bool,
+
+    /// Whether the header was yielded (or skipped because the iterator is
+    /// empty).
+    started: bool,
+
+    /// Whether the footer was yielded (or skipped because the iterator is
+    /// empty).
+    finished: bool,
+}
+
+impl<I: Iterator> Surround<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter: iter.with_status(),
+            even_if_empty: false,
+            started: false,
+            finished: false,
+        }
+    }
+
+    /// Makes this iterator yield the header and footer even if the underlying
+    /// iterator is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::{Element, IterStatusExt};
+    ///
+    /// let v: Vec<Element<u32>> = None.into_iter().surround().even_if_empty().collect();
+    /// assert_eq!(v, [Element::Header, Element::Footer]);
+    /// ```
+    pub fn even_if_empty(mut self) -> Self {
+        self.even_if_empty = true;
+        self
+    }
+}
+
+impl<I> Clone for Surround<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            even_if_empty: self.even_if_empty,
+            started: self.started,
+            finished: self.finished,
+        }
+    }
+}
+
+impl<I> fmt::Debug for Surround<I>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Surround")
+            .field("iter", &self.iter)
+            .field("even_if_empty", &self.even_if_empty)
+            .field("started", &self.started)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+impl<I: Iterator> Iterator for Surround<I> {
+    type Item = Element<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        if !self.started {
+            self.started = true;
+            if self.even_if_empty || self.iter.peek_item().is_some() {
+                return Some(Element::Header);
+            }
+
+            self.finished = true;
+            return None;
+        }
+
+        match self.iter.next() {
+            Some((item, status)) => Some(Element::Item(item, status)),
+            None => {
+                self.finished = true;
+                Some(Element::Footer)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            return (0, Some(0));
+        }
+
+        let (lower, upper) = self.iter.size_hint();
+        let with_extra = |n: usize| {
+            if self.started {
+                n.checked_add(1)
+            } else if n == 0 && !self.even_if_empty {
+                Some(0)
+            } else {
+                n.checked_add(2)
+            }
+        };
+
+        (
+            with_extra(lower).unwrap_or(usize::MAX),
+            upper.and_then(with_extra),
+        )
+    }
+}
+
+impl<I: Iterator> FusedIterator for Surround<I> {}
+
+/// An element yielded by [`Surround`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Element<T> {
+    /// Yielded once before the first item.
+    Header,
+
+    /// An item of the underlying iterator together with its status.
+    Item(T, Status),
+
+    /// Yielded once after the last item.
+    Footer,
+}
+
+/// Iterator adapter which yields every item together with its previous and
+/// next item. See
+/// [`IterStatusExt::with_neighbors`][crate::IterStatusExt::with_neighbors]
+/// for more information.
+pub struct WithNeighbors<I: Iterator> {
+    iter: WithStatus<I>,
+    prev: Option<I::Item>,
+}
+
+impl<I: Iterator> WithNeighbors<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter: iter.with_status(),
+            prev: None,
+        }
+    }
+}
+
+impl<I> Clone for WithNeighbors<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            prev: self.prev.clone(),
+        }
+    }
+}
+
+impl<I> fmt::Debug for WithNeighbors<I>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WithNeighbors")
+            .field("iter", &self.iter)
+            .field("prev", &self.prev)
+            .finish()
+    }
+}
+
+impl<I> Iterator for WithNeighbors<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = (Option<I::Item>, I::Item, Option<I::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (item, _) = self.iter.next()?;
+        let next = self.iter.peek_item().cloned();
+        let prev = self.prev.replace(item.clone());
+
+        Some((prev, item, next))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> FusedIterator for WithNeighbors<I>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+{}
+
+impl<I> ExactSizeIterator for WithNeighbors<I>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+/// Iterator adapter which yields overlapping windows of `N` items together
+/// with the status of each window. See
+/// [`IterStatusExt::windows_with_status`][crate::IterStatusExt::windows_with_status]
+/// for more information.
+pub struct WindowsWithStatus<I: Iterator, const N: usize> {
+    iter: WithStatus<I>,
+
+    /// The items of the window yielded last (or of the first window while it
+    /// is being filled).
+    buffer: VecDeque<I::Item>,
+
+    /// The index of the window that will be yielded next.
+    index: usize,
+}
+
+impl<I: Iterator, const N: usize> WindowsWithStatus<I, N> {
+    pub(crate) fn new(iter: I) -> Self {
+        assert!(N != 0, "window size must be non-zero");
+
+        Self {
+            iter: iter.with_status(),
+            buffer: VecDeque::with_capacity(N),
+            index: 0,
+        }
+    }
+}
+
+impl<I, const N: usize> Clone for WindowsWithStatus<I, N>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            buffer: self.buffer.clone(),
+            index: self.index,
+        }
+    }
+}
+
+impl<I, const N: usize> fmt::Debug for WindowsWithStatus<I, N>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WindowsWithStatus")
+            .field("iter", &self.iter)
+            .field("buffer", &self.buffer)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+impl<I, const N: usize> Iterator for WindowsWithStatus<I, N>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = ([I::Item; N], Status);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buffer.len() == N {
+            self.buffer.pop_front();
+        }
+
+        // The window is the last one if its last item is the last one, so we
+        // just use the status of that item.
+        let mut item_status = None;
+        while self.buffer.len() < N {
+            let (item, status) = self.iter.next()?;
+            self.buffer.push_back(item);
+            item_status = Some(status);
+        }
+        let item_status = item_status.expect("N is non-zero");
+
+        let window = std::array::from_fn(|i| self.buffer[i].clone());
+        let status = Status {
+            index: self.index,
+            last: item_status.last,
+            remaining: item_status.remaining,
+        };
+        self.index += 1;
+
+        Some((window, status))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.buffer.len();
+        let (lower, upper) = self.iter.size_hint();
+        let windows = |n: usize| {
+            if buffered == N {
+                Some(n)
+            } else {
+                n.checked_add(buffered + 1).map(|n| n.saturating_sub(N))
+            }
+        };
+
+        (
+            windows(lower).unwrap_or(usize::MAX),
+            upper.and_then(windows),
+        )
+    }
+}
+
+impl<I, const N: usize> FusedIterator for WindowsWithStatus<I, N>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+{}
+
+impl<I, const N: usize> ExactSizeIterator for WindowsWithStatus<I, N>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+{}
+
+/// Iterator adapter like `Scan` whose closure also receives the status of each
+/// item. See
+/// [`IterStatusExt::scan_with_status`][crate::IterStatusExt::scan_with_status]
+/// for more information.
+pub struct ScanWithStatus<I: Iterator, St, F> {
+    iter: WithStatus<I>,
+    state: St,
+    f: F,
+}
+
+impl<I: Iterator, St, F> ScanWithStatus<I, St, F> {
+    pub(crate) fn new(iter: I, state: St, f: F) -> Self {
+        Self {
+            iter: iter.with_status(),
+            state,
+            f,
+        }
+    }
+}
+
+impl<I, St, F> Clone for ScanWithStatus<I, St, F>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    St: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            state: self.state.clone(),
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<I, St, F> fmt::Debug for ScanWithStatus<I, St, F>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+    St: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ScanWithStatus")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+impl<I, St, F, B> Iterator for ScanWithStatus<I, St, F>
+where
+    I: Iterator,
+    F: FnMut(&mut St, I::Item, Status) -> Option<B>,
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (item, status) = self.iter.next()?;
+        (self.f)(&mut self.state, item, status)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // The closure can stop the iteration at any point.
+        (0, self.iter.size_hint().1)
+    }
+}
+
+/// Iterator adapter which maps every item together with its status. See
+/// [`IterStatusExt::map_with_status`][crate::IterStatusExt::map_with_status]
+/// for more information.
+pub struct MapWithStatus<I: Iterator, F> {
+    iter: WithStatus<I>,
+    f: F,
+}
+
+impl<I: Iterator, F> MapWithStatus<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter: iter.with_status(),
+            f,
+        }
+    }
+}
+
+impl<I, F> Clone for MapWithStatus<I, F>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<I, F> fmt::Debug for MapWithStatus<I, F>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MapWithStatus")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, F, B> Iterator for MapWithStatus<I, F>
+where
+    I: Iterator,
+    F: FnMut(I::Item, Status) -> B,
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (item, status) = self.iter.next()?;
+        Some((self.f)(item, status))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, |acc, (item, status)| g(acc, f(item, status)))
+    }
+}
+
+impl<I, F, B> DoubleEndedIterator for MapWithStatus<I, F>
+where
+    I: DoubleEndedIterator + ExactSizeIterator,
+    F: FnMut(I::Item, Status) -> B,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (item, status) = self.iter.next_back()?;
+        Some((self.f)(item, status))
+    }
+}
+
+impl<I, F, B> FusedIterator for MapWithStatus<I, F>
+where
+    I: FusedIterator,
+    F: FnMut(I::Item, Status) -> B,
+{}
+
+impl<I, F, B> ExactSizeIterator for MapWithStatus<I, F>
+where
+    I: ExactSizeIterator,
+    F: FnMut(I::Item, Status) -> B,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+/// Iterator adapter which filters items with a predicate that also receives
+/// their status. See
+/// [`IterStatusExt::filter_with_status`][crate::IterStatusExt::filter_with_status]
+/// for more information.
+pub struct FilterWithStatus<I: Iterator, P> {
+    iter: WithStatus<I>,
+    predicate: P,
+}
+
+impl<I: Iterator, P> FilterWithStatus<I, P> {
+    pub(crate) fn new(iter: I, predicate: P) -> Self {
+        Self {
+            iter: iter.with_status(),
+            predicate,
+        }
+    }
+}
+
+impl<I, P> Clone for FilterWithStatus<I, P>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    P: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            predicate: self.predicate.clone(),
+        }
+    }
+}
+
+impl<I, P> fmt::Debug for FilterWithStatus<I, P>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FilterWithStatus")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, P> Iterator for FilterWithStatus<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item, Status) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let predicate = &mut self.predicate;
+        self.iter.find(|(item, status)| predicate(item, *status)).map(|(item, _)| item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I, P> DoubleEndedIterator for FilterWithStatus<I, P>
+where
+    I: DoubleEndedIterator + ExactSizeIterator,
+    P: FnMut(&I::Item, Status) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let predicate = &mut self.predicate;
+        self.iter.rfind(|(item, status)| predicate(item, *status)).map(|(item, _)| item)
+    }
+}
+
+impl<I, P> FusedIterator for FilterWithStatus<I, P>
+where
+    I: FusedIterator,
+    P: FnMut(&I::Item, Status) -> bool,
+{}
+
+/// Iterator adapter which calls a closure with a reference to every item and
+/// its status. See
+/// [`IterStatusExt::inspect_with_status`][crate::IterStatusExt::inspect_with_status]
+/// for more information.
+pub struct InspectWithStatus<I: Iterator, F> {
+    iter: WithStatus<I>,
+    f: F,
+}
+
+impl<I: Iterator, F> InspectWithStatus<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter: iter.with_status(),
+            f,
+        }
+    }
+}
+
+impl<I, F> Clone for InspectWithStatus<I, F>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<I, F> fmt::Debug for InspectWithStatus<I, F>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("InspectWithStatus")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, F> Iterator for InspectWithStatus<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, Status),
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (item, status) = self.iter.next()?;
+        (self.f)(&item, status);
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> DoubleEndedIterator for InspectWithStatus<I, F>
+where
+    I: DoubleEndedIterator + ExactSizeIterator,
+    F: FnMut(&I::Item, Status),
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (item, status) = self.iter.next_back()?;
+        (self.f)(&item, status);
+        Some(item)
+    }
+}
+
+impl<I, F> FusedIterator for InspectWithStatus<I, F>
+where
+    I: FusedIterator,
+    F: FnMut(&I::Item, Status),
+{}
+
+impl<I, F> ExactSizeIterator for InspectWithStatus<I, F>
+where
+    I: ExactSizeIterator,
+    F: FnMut(&I::Item, Status),
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
@@ -0,0 +1,74 @@
+use std::iter::FusedIterator;
+
+use crate::Status;
+
+/// Iterator wrapper which determines the status by counting. See
+/// [`IterStatusExt::with_status_counted`][crate::IterStatusExt::with_status_counted]
+/// for more information.
+pub struct WithStatusCounted<I: ExactSizeIterator> {
+    iter: I,
+
+    /// The index of the item that will be yielded next.
+    index: usize,
+
+    /// The number of items already yielded by `next_back`.
+    back: usize,
+}
+
+impl<I: ExactSizeIterator> WithStatusCounted<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter,
+            index: 0,
+            back: 0,
+        }
+    }
+}
+
+impl<I: ExactSizeIterator> Iterator for WithStatusCounted<I> {
+    type Item = (I::Item, Status);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+
+        // Items already yielded from the back still come after this item.
+        let remaining = self.iter.len() + self.back;
+        let status = Status {
+            index: self.index,
+            last: remaining == 0,
+            remaining: Some(remaining),
+        };
+        self.index += 1;
+
+        Some((item, status))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> DoubleEndedIterator for WithStatusCounted<I>
+where
+    I: DoubleEndedIterator + ExactSizeIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next_back()?;
+
+        let status = Status {
+            index: self.index + self.iter.len(),
+            last: self.back == 0,
+            remaining: Some(self.back),
+        };
+        self.back += 1;
+
+        Some((item, status))
+    }
+}
+
+impl<I: ExactSizeIterator + FusedIterator> FusedIterator for WithStatusCounted<I> {}
+impl<I: ExactSizeIterator> ExactSizeIterator for WithStatusCounted<I> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
@@ -0,0 +1,163 @@
+use std::fmt;
+
+use fallible_iterator::FallibleIterator;
+
+use crate::Status;
+
+/// Fallible iterator wrapper which keeps track of the status. See
+/// [`FallibleIterStatusExt::with_status`] for more information.
+pub struct FallibleWithStatus<I: FallibleIterator> {
+    iter: I,
+
+    /// The item following the one that was yielded last.
+    peeked: Option<I::Item>,
+
+    /// The error we got while looking for the next item. It is returned by
+    /// the next call to `next`.
+    error: Option<I::Error>,
+
+    /// The index of the item that will be yielded next.
+    index: usize,
+}
+
+impl<I> Clone for FallibleWithStatus<I>
+where
+    I: FallibleIterator + Clone,
+    I::Item: Clone,
+    I::Error: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            peeked: self.peeked.clone(),
+            error: self.error.clone(),
+            index: self.index,
+        }
+    }
+}
+
+impl<I> fmt::Debug for FallibleWithStatus<I>
+where
+    I: FallibleIterator + fmt::Debug,
+    I::Item: fmt::Debug,
+    I::Error: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FallibleWithStatus")
+            .field("iter", &self.iter)
+            .field("peeked", &self.peeked)
+            .field("error", &self.error)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+impl<I: FallibleIterator> FallibleIterator for FallibleWithStatus<I> {
+    type Item = (I::Item, Status);
+    type Error = I::Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        if let Some(e) = self.error.take() {
+            return Err(e);
+        }
+
+        let item = match self.peeked.take() {
+            Some(item) => item,
+            None => match self.iter.next()? {
+                Some(item) => item,
+                None => return Ok(None),
+            },
+        };
+
+        // Check if there is another item. If we get an error instead, we
+        // yield the current item first and return the error afterwards.
+        let last = match self.iter.next() {
+            Ok(next) => {
+                self.peeked = next;
+                self.peeked.is_none()
+            }
+            Err(e) => {
+                self.error = Some(e);
+                false
+            }
+        };
+
+        let status = Status {
+            index: self.index,
+            last,
+            remaining: if last {
+                Some(0)
+            } else if self.error.is_some() {
+                None
+            } else {
+                match self.size_hint() {
+                    (lower, Some(upper)) if lower == upper => Some(lower),
+                    _ => None,
+                }
+            },
+        };
+        self.index += 1;
+
+        Ok(Some((item, status)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let peeked = self.peeked.is_some() as usize;
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.saturating_add(peeked),
+            upper.and_then(|upper| upper.checked_add(peeked)),
+        )
+    }
+}
+
+/// Adds the `with_status` method to all fallible iterators. Requires the
+/// `fallible-iterator` feature.
+pub trait FallibleIterStatusExt: FallibleIterator + Sized {
+    /// Creates a fallible iterator that yields the original items paired with
+    /// a [`Status`], just like [`IterStatusExt::with_status`][crate::IterStatusExt::with_status]
+    /// does for iterators.
+    ///
+    /// Errors are passed through untouched. If the underlying iterator
+    /// returns an error while we check whether there is another item, the
+    /// current item is yielded first (as not being the last one) and the
+    /// error is returned by the following call.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate fallible_iterator;
+    /// # extern crate splop;
+    /// use fallible_iterator::FallibleIterator;
+    /// use splop::FallibleIterStatusExt;
+    ///
+    /// let rows = vec![Ok("anna"), Ok("bob"), Err("connection lost"), Ok("peter")];
+    /// let mut iter = fallible_iterator::convert(rows.into_iter()).with_status();
+    ///
+    /// let (name, status) = iter.next().unwrap().unwrap();
+    /// assert_eq!(name, "anna");
+    /// assert!(status.is_first());
+    ///
+    /// let (name, status) = iter.next().unwrap().unwrap();
+    /// assert_eq!(name, "bob");
+    /// assert!(!status.is_last());
+    ///
+    /// assert_eq!(iter.next(), Err("connection lost"));
+    ///
+    /// let (name, status) = iter.next().unwrap().unwrap();
+    /// assert_eq!(name, "peter");
+    /// assert!(status.is_last());
+    /// ```
+    fn with_status(self) -> FallibleWithStatus<Self>;
+}
+
+impl<I: FallibleIterator> FallibleIterStatusExt for I {
+    fn with_status(self) -> FallibleWithStatus<Self> {
+        FallibleWithStatus {
+            iter: self,
+            peeked: None,
+            error: None,
+            index: 0,
+        }
+    }
+}
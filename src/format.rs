@@ -0,0 +1,120 @@
+use std::fmt;
+
+use crate::Status;
+
+/// Decides which separator is placed between two items of a list.
+///
+/// All joining functions and types of this crate accept a `ListFormat` as
+/// separator. It's implemented for `str`, `String` and `char` (always using
+/// the same separator) and by [`CommaList`], [`SemicolonList`],
+/// [`GermanUnd`] and [`HumanJoin`][crate::HumanJoin].
+///
+/// There are three exceptions which only accept a plain separator:
+/// [`Separated`][crate::Separated] receives items one by one and thus can't
+/// know which item is the last one, the same applies to
+/// [`IterStatusExt::collect_with_sep`][crate::IterStatusExt::collect_with_sep]
+/// which collects into a `Separated`, and
+/// [`WriteSeparated`][crate::WriteSeparated] writes raw bytes instead of
+/// text.
+///
+/// # Example
+///
+/// ```
+/// use std::fmt;
+/// use splop::{ListFormat, Status};
+///
+/// /// Separates the last item with an arrow.
+/// struct Arrow;
+///
+/// impl ListFormat for Arrow {
+///     fn write_separator(&self, w: &mut dyn fmt::Write, next: Status) -> fmt::Result {
+///         w.write_str(if next.is_last() { " => " } else { ", " })
+///     }
+/// }
+///
+/// assert_eq!(splop::join(&["a", "b", "c"], Arrow), "a, b => c");
+/// ```
+pub trait ListFormat {
+    /// Writes the separator that is placed before the item with the status
+    /// `next`. This is never called for the first item.
+    fn write_separator(&self, w: &mut dyn fmt::Write, next: Status) -> fmt::Result;
+}
+
+impl ListFormat for str {
+    fn write_separator(&self, w: &mut dyn fmt::Write, _: Status) -> fmt::Result {
+        w.write_str(self)
+    }
+}
+
+impl ListFormat for String {
+    fn write_separator(&self, w: &mut dyn fmt::Write, _: Status) -> fmt::Result {
+        w.write_str(self)
+    }
+}
+
+impl ListFormat for char {
+    fn write_separator(&self, w: &mut dyn fmt::Write, _: Status) -> fmt::Result {
+        w.write_char(*self)
+    }
+}
+
+impl<T: ListFormat + ?Sized> ListFormat for &T {
+    fn write_separator(&self, w: &mut dyn fmt::Write, next: Status) -> fmt::Result {
+        (**self).write_separator(w, next)
+    }
+}
+
+/// Separates all items with a comma: "a, b, c".
+///
+/// # Example
+///
+/// ```
+/// use splop::CommaList;
+///
+/// assert_eq!(splop::join(&[1, 2, 3], CommaList), "1, 2, 3");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CommaList;
+
+impl ListFormat for CommaList {
+    fn write_separator(&self, w: &mut dyn fmt::Write, _: Status) -> fmt::Result {
+        w.write_str(", ")
+    }
+}
+
+/// Separates all items with a semicolon: "a; b; c".
+///
+/// # Example
+///
+/// ```
+/// use splop::SemicolonList;
+///
+/// assert_eq!(splop::join(&[1, 2, 3], SemicolonList), "1; 2; 3");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SemicolonList;
+
+impl ListFormat for SemicolonList {
+    fn write_separator(&self, w: &mut dyn fmt::Write, _: Status) -> fmt::Result {
+        w.write_str("; ")
+    }
+}
+
+/// Formats a German list, which has no comma before the "und": "a, b und c".
+///
+/// # Example
+///
+/// ```
+/// use splop::GermanUnd;
+///
+/// let s = splop::join(&["Äpfel", "Birnen", "Kirschen"], GermanUnd);
+/// assert_eq!(s, "Äpfel, Birnen und Kirschen");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct GermanUnd;
+
+impl ListFormat for GermanUnd {
+    fn write_separator(&self, w: &mut dyn fmt::Write, next: Status) -> fmt::Result {
+        w.write_str(if next.is_last() { " und " } else { ", " })
+    }
+}
@@ -0,0 +1,615 @@
+use std::{
+    cell::Cell,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// Like [`SkipFirst`][crate::SkipFirst], but all methods take `&self`.
+///
+/// The state is stored in a [`Cell`], so this can be used inside `Fn`
+/// closures and everywhere else you only have a shared reference. It's not
+/// `Sync`, though.
+///
+/// # Example
+///
+/// ```
+/// use splop::SkipFirstCell;
+///
+/// let comma = SkipFirstCell::new();
+/// let s: String = ["peter", "ingrid", "barbara"].iter()
+///     .map(|name| format!("{}{}", comma.skip_first(|| ", ").unwrap_or(""), name))
+///     .collect();
+///
+/// assert_eq!(s, "peter, ingrid, barbara");
+/// ```
+#[derive(Clone, Debug)]
+pub struct SkipFirstCell {
+    first: Cell<bool>,
+}
+
+impl Default for SkipFirstCell {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SkipFirstCell {
+    /// Creates a new instance of `SkipFirstCell`.
+    pub const fn new() -> Self {
+        Self {
+            first: Cell::new(true),
+        }
+    }
+
+    /// Executes the given function, except the first time this method is
+    /// called on this instance. See
+    /// [`SkipFirst::skip_first`][crate::SkipFirst::skip_first].
+    pub fn skip_first<R>(&self, f: impl FnOnce() -> R) -> Option<R> {
+        if self.check() {
+            Some(f())
+        } else {
+            None
+        }
+    }
+
+    /// Returns `false` the first time this method is called on this instance
+    /// and `true` on every later call. See
+    /// [`SkipFirst::check`][crate::SkipFirst::check].
+    pub fn check(&self) -> bool {
+        !self.first.replace(false)
+    }
+
+    /// Returns `true` if the first call (which was skipped) already happened.
+    pub fn has_skipped(&self) -> bool {
+        !self.first.get()
+    }
+
+    /// Resets this instance to its initial state, so that the next call is
+    /// skipped again.
+    pub fn reset(&self) {
+        self.first.set(true);
+    }
+}
+
+/// Like [`SkipFirst`][crate::SkipFirst], but all methods take `&self` and it
+/// can be shared between threads.
+///
+/// `new` is a `const fn`, so this can be used in `static`s. Exactly one call
+/// is skipped, even if multiple threads call it at the same time. The flag
+/// is swapped with `AcqRel` ordering, so everything a thread did before the
+/// skipped call is visible to all calls that execute their function.
+///
+/// # Example
+///
+/// ```
+/// use splop::AtomicSkipFirst;
+///
+/// static NOT_FIRST: AtomicSkipFirst = AtomicSkipFirst::new();
+///
+/// fn is_repeated_call() -> bool {
+///     NOT_FIRST.check()
+/// }
+///
+/// assert!(!is_repeated_call());
+/// assert!(is_repeated_call());
+/// ```
+#[derive(Debug)]
+pub struct AtomicSkipFirst {
+    first: AtomicBool,
+}
+
+impl Default for AtomicSkipFirst {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AtomicSkipFirst {
+    /// Creates a new instance of `AtomicSkipFirst`.
+    pub const fn new() -> Self {
+        Self {
+            first: AtomicBool::new(true),
+        }
+    }
+
+    /// Executes the given function, except the first time this method is
+    /// called on this instance. See
+    /// [`SkipFirst::skip_first`][crate::SkipFirst::skip_first].
+    pub fn skip_first<R>(&self, f: impl FnOnce() -> R) -> Option<R> {
+        if self.check() {
+            Some(f())
+        } else {
+            None
+        }
+    }
+
+    /// Returns `false` the first time this method is called on this instance
+    /// and `true` on every later call. See
+    /// [`SkipFirst::check`][crate::SkipFirst::check].
+    pub fn check(&self) -> bool {
+        !self.first.swap(false, Ordering::AcqRel)
+    }
+
+    /// Returns `true` if the first call (which was skipped) already happened.
+    pub fn has_skipped(&self) -> bool {
+        !self.first.load(Ordering::Acquire)
+    }
+
+    /// Resets this instance to its initial state, so that the next call is
+    /// skipped again.
+    pub fn reset(&self) {
+        self.first.store(true, Ordering::Release);
+    }
+}
+
+/// Like [`SkipFirst`][crate::SkipFirst], but skips the first `n` calls
+/// instead of only the first one.
+///
+/// # Example
+///
+/// ```
+/// use splop::SkipFirstN;
+///
+/// let mut v = Vec::new();
+/// let mut warm_up = SkipFirstN::new(2);
+/// for i in 0..5 {
+///     warm_up.skip_first(|| v.push(i));
+/// }
+///
+/// assert_eq!(v, [2, 3, 4]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SkipFirstN {
+    n: usize,
+    skipped: usize,
+}
+
+impl SkipFirstN {
+    /// Creates a new instance that skips the first `n` calls.
+    pub const fn new(n: usize) -> Self {
+        Self { n, skipped: 0 }
+    }
+
+    /// Executes the given function, except for the first `n` times this
+    /// method is called on this instance.
+    pub fn skip_first<R>(&mut self, f: impl FnOnce() -> R) -> Option<R> {
+        if self.check() {
+            Some(f())
+        } else {
+            None
+        }
+    }
+
+    /// Returns `false` for the first `n` calls of this method and `true` on
+    /// every later call.
+    pub fn check(&mut self) -> bool {
+        if self.skipped < self.n {
+            self.skipped += 1;
+            false
+        } else {
+            true
+        }
+    }
+
+    /// Returns `true` if all `n` calls that should be skipped already
+    /// happened.
+    pub const fn has_skipped(&self) -> bool {
+        self.skipped == self.n
+    }
+
+    /// Resets this instance to its initial state, so that the next `n` calls
+    /// are skipped again.
+    pub fn reset(&mut self) {
+        self.skipped = 0;
+    }
+}
+
+/// A gate that stays closed for the first `n` calls and executes its
+/// function on every call afterwards. This is just another name for
+/// [`SkipFirstN`].
+///
+/// # Example
+///
+/// ```
+/// use splop::AfterN;
+///
+/// let mut lines = Vec::new();
+/// let mut sep = AfterN::new(2);
+/// for row in &["header", "units", "1", "2"] {
+///     sep.skip_first(|| lines.push("--"));
+///     lines.push(row);
+/// }
+///
+/// assert_eq!(lines, ["header", "units", "--", "1", "--", "2"]);
+/// ```
+pub type AfterN = SkipFirstN;
+
+/// The inverse of [`SkipFirst`][crate::SkipFirst]: executes a function only
+/// the first time and never again.
+///
+/// # Example
+///
+/// ```
+/// use splop::{RunFirst, SkipFirst};
+///
+/// let mut s = String::new();
+/// let mut header = RunFirst::new();
+/// let mut comma = SkipFirst::new();
+/// for name in &["peter", "ingrid"] {
+///     header.run_first(|| s.push_str("Names: "));
+///     comma.skip_first(|| s.push_str(", "));
+///     s.push_str(name);
+/// }
+///
+/// assert_eq!(s, "Names: peter, ingrid");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RunFirst {
+    first: bool,
+}
+
+impl Default for RunFirst {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RunFirst {
+    /// Creates a new instance of `RunFirst`.
+    pub const fn new() -> Self {
+        Self { first: true }
+    }
+
+    /// Executes the given function only the first time this method is called
+    /// on this instance. Returns the function's result or `None` if it
+    /// wasn't executed.
+    pub fn run_first<R>(&mut self, f: impl FnOnce() -> R) -> Option<R> {
+        if self.check() {
+            Some(f())
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` the first time this method is called on this instance
+    /// and `false` on every later call.
+    pub fn check(&mut self) -> bool {
+        let first = self.first;
+        self.first = false;
+        first
+    }
+
+    /// Returns `true` if the first call already happened.
+    pub const fn has_run(&self) -> bool {
+        !self.first
+    }
+
+    /// Resets this instance to its initial state, so that the next call
+    /// executes its function again.
+    pub fn reset(&mut self) {
+        self.first = true;
+    }
+}
+
+/// Executes a function at most `n` times and then never again, e.g. to only
+/// show the first few warnings.
+///
+/// # Example
+///
+/// ```
+/// use splop::RunAtMost;
+///
+/// let mut warnings = Vec::new();
+/// let mut limit = RunAtMost::new(2);
+/// for i in 0..5 {
+///     limit.run(|| warnings.push(i));
+/// }
+///
+/// assert_eq!(warnings, [0, 1]);
+/// assert!(limit.is_exhausted());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RunAtMost {
+    n: usize,
+    runs: usize,
+}
+
+impl RunAtMost {
+    /// Creates a new instance that executes its function at most `n` times.
+    pub const fn new(n: usize) -> Self {
+        Self { n, runs: 0 }
+    }
+
+    /// Executes the given function, unless it was already executed `n`
+    /// times. Returns the function's result or `None` if it wasn't executed.
+    pub fn run<R>(&mut self, f: impl FnOnce() -> R) -> Option<R> {
+        if self.check() {
+            Some(f())
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` for the first `n` calls of this method and `false` on
+    /// every later call.
+    pub fn check(&mut self) -> bool {
+        if self.runs < self.n {
+            self.runs += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns how many more times the function will be executed.
+    pub const fn remaining(&self) -> usize {
+        self.n - self.runs
+    }
+
+    /// Returns `true` if the function won't be executed anymore.
+    pub const fn is_exhausted(&self) -> bool {
+        self.runs == self.n
+    }
+
+    /// Resets this instance to its initial state, so that the function is
+    /// executed `n` more times.
+    pub fn reset(&mut self) {
+        self.runs = 0;
+    }
+}
+
+/// Executes a function on every `n`-th call, e.g. to flush a buffer every
+/// 100 iterations.
+///
+/// By default, the function runs on call number `n`, `2n`, `3n`, ...
+/// (counting from one). With [`with_offset`][EveryNth::with_offset], the
+/// first run can be moved.
+///
+/// # Example
+///
+/// ```
+/// use splop::EveryNth;
+///
+/// let mut v = Vec::new();
+/// let mut flush = EveryNth::new(3);
+/// for i in 1..=7 {
+///     flush.run(|| v.push(i));
+/// }
+/// assert_eq!(v, [3, 6]);
+///
+/// let mut v = Vec::new();
+/// let mut flush = EveryNth::with_offset(3, 1);
+/// for i in 1..=7 {
+///     flush.run(|| v.push(i));
+/// }
+/// assert_eq!(v, [1, 4, 7]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EveryNth {
+    n: usize,
+    offset: usize,
+
+    /// The number of calls until the next run (including the run).
+    until_next: usize,
+}
+
+impl EveryNth {
+    /// Creates a new instance that executes its function on call number `n`,
+    /// `2n`, `3n`, ... (counting from one).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub const fn new(n: usize) -> Self {
+        Self::with_offset(n, n)
+    }
+
+    /// Creates a new instance that executes its function on call number
+    /// `offset`, `offset + n`, `offset + 2n`, ... (counting from one). An
+    /// `offset` of 0 behaves like `n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub const fn with_offset(n: usize, offset: usize) -> Self {
+        assert!(n != 0, "`n` must not be 0 in `EveryNth`");
+
+        let offset = if offset == 0 { n } else { offset };
+        Self {
+            n,
+            offset,
+            until_next: offset,
+        }
+    }
+
+    /// Executes the given function if this is the `n`-th call since the last
+    /// run. Returns the function's result or `None` if it wasn't executed.
+    pub fn run<R>(&mut self, f: impl FnOnce() -> R) -> Option<R> {
+        if self.check() {
+            Some(f())
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the function should run on this call and `false`
+    /// otherwise.
+    pub fn check(&mut self) -> bool {
+        self.until_next -= 1;
+        if self.until_next == 0 {
+            self.until_next = self.n;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Resets this instance to its initial state.
+    pub fn reset(&mut self) {
+        self.until_next = self.offset;
+    }
+}
+
+/// Executes a function exactly once: on the `n`-th call (counting from one).
+///
+/// # Example
+///
+/// ```
+/// use splop::NthTime;
+///
+/// let mut out = Vec::new();
+/// let mut continued = NthTime::new(2);
+/// for page in 1..=3 {
+///     continued.run(|| out.push("(continued)"));
+///     out.push(["one", "two", "three"][page - 1]);
+/// }
+///
+/// assert_eq!(out, ["one", "(continued)", "two", "three"]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NthTime {
+    n: usize,
+    calls: usize,
+}
+
+impl NthTime {
+    /// Creates a new instance that executes its function on the `n`-th call.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub const fn new(n: usize) -> Self {
+        assert!(n != 0, "`n` must not be 0 in `NthTime`");
+        Self { n, calls: 0 }
+    }
+
+    /// Executes the given function if this is the `n`-th call. Returns the
+    /// function's result or `None` if it wasn't executed.
+    pub fn run<R>(&mut self, f: impl FnOnce() -> R) -> Option<R> {
+        if self.check() {
+            Some(f())
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` on the `n`-th call of this method and `false` on all
+    /// others.
+    pub fn check(&mut self) -> bool {
+        if self.calls < self.n {
+            self.calls += 1;
+            self.calls == self.n
+        } else {
+            false
+        }
+    }
+
+    /// Returns `true` if the `n`-th call already happened.
+    pub const fn has_run(&self) -> bool {
+        self.calls == self.n
+    }
+
+    /// Resets this instance to its initial state.
+    pub fn reset(&mut self) {
+        self.calls = 0;
+    }
+}
+
+/// Alternates between two functions: the first call executes the first
+/// function, the second call the second function, the third call the first
+/// function again and so on.
+///
+/// # Example
+///
+/// ```
+/// use splop::Alternator;
+///
+/// let mut rows = Vec::new();
+/// let mut zebra = Alternator::new();
+/// for name in &["peter", "ingrid", "barbara"] {
+///     let class = zebra.alternate(|| "light", || "dark");
+///     rows.push(format!("<tr class=\"{}\">{}</tr>", class, name));
+/// }
+///
+/// assert_eq!(rows, [
+///     "<tr class=\"light\">peter</tr>",
+///     "<tr class=\"dark\">ingrid</tr>",
+///     "<tr class=\"light\">barbara</tr>",
+/// ]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Alternator {
+    second: bool,
+}
+
+impl Default for Alternator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Alternator {
+    /// Creates a new instance. The next call executes the first function.
+    pub const fn new() -> Self {
+        Self { second: false }
+    }
+
+    /// Executes `first` on odd calls (the first, third, ...) and `second` on
+    /// even calls. Returns the value returned by the executed function.
+    pub fn alternate<R>(&mut self, first: impl FnOnce() -> R, second: impl FnOnce() -> R) -> R {
+        let run_second = self.second;
+        self.second = !self.second;
+        if run_second {
+            second()
+        } else {
+            first()
+        }
+    }
+
+    /// Resets this instance to its initial state, so that the next call
+    /// executes the first function.
+    pub fn reset(&mut self) {
+        self.second = false;
+    }
+}
+
+/// A boolean that flips its value every time [`flip`][Toggle::flip] is
+/// called.
+///
+/// # Example
+///
+/// ```
+/// use splop::Toggle;
+///
+/// let mut highlight = Toggle::new(false);
+/// let v: Vec<_> = (0..4).map(|_| highlight.flip()).collect();
+///
+/// assert_eq!(v, [true, false, true, false]);
+/// assert!(!highlight.get());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Toggle {
+    state: bool,
+}
+
+impl Toggle {
+    /// Creates a new toggle with the given initial value.
+    pub const fn new(initial: bool) -> Self {
+        Self { state: initial }
+    }
+
+    /// Flips the value and returns the new value.
+    pub fn flip(&mut self) -> bool {
+        self.state = !self.state;
+        self.state
+    }
+
+    /// Returns the current value.
+    pub const fn get(&self) -> bool {
+        self.state
+    }
+
+    /// Sets the current value.
+    pub fn set(&mut self, value: bool) {
+        self.state = value;
+    }
+}
@@ -0,0 +1,713 @@
+use std::{fmt, iter::FusedIterator};
+
+use crate::{exact_len, IterStatusExt, Status, WithStatus};
+
+/// The status of an item that belongs to a group of consecutive items.
+///
+/// Besides the status of the item within the whole iterator
+/// ([`global`][GroupStatus::global]), this also stores the status of the item
+/// within its group ([`local`][GroupStatus::local]) and the index of the
+/// group.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct GroupStatus {
+    global: Status,
+    local: Status,
+    group_index: usize,
+}
+
+impl GroupStatus {
+    /// Returns the status of the item within the whole iterator.
+    pub const fn global(&self) -> Status {
+        self.global
+    }
+
+    /// Returns the status of the item within its group.
+    ///
+    /// The number of [`remaining`][Status::remaining] items in the group is
+    /// only known for the last item of the group.
+    pub const fn local(&self) -> Status {
+        self.local
+    }
+
+    /// Returns the zero-based index of the group this item belongs to.
+    pub const fn group_index(&self) -> usize {
+        self.group_index
+    }
+
+    /// Returns `true` if this is the first item of its group.
+    pub const fn is_group_first(&self) -> bool {
+        self.local.is_first()
+    }
+
+    /// Returns `true` if this is the last item of its group.
+    pub const fn is_group_last(&self) -> bool {
+        self.local.is_last()
+    }
+}
+
+/// Splits the items of an iterator into groups. Used by all adapters
+/// yielding a `GroupStatus`, which only differ in how they decide where a
+/// group ends.
+struct Grouper<I: Iterator> {
+    iter: WithStatus<I>,
+
+    /// The index of the next item within its group.
+    local_index: usize,
+
+    /// The index of the group the next item belongs to.
+    group_index: usize,
+}
+
+impl<I: Iterator> Grouper<I> {
+    fn new(iter: I) -> Self {
+        Self {
+            iter: iter.with_status(),
+            local_index: 0,
+            group_index: 0,
+        }
+    }
+
+    /// Yields the next item. `is_boundary` is called with the item and the
+    /// one following it and returns whether a new group starts between them.
+    fn next_by<F>(&mut self, is_boundary: F) -> Option<(I::Item, GroupStatus)>
+    where
+        F: FnOnce(&I::Item, &I::Item) -> bool,
+    {
+        let (item, global) = self.iter.next()?;
+        let group_last = match self.iter.peek_item() {
+            Some(next) => is_boundary(&item, next),
+            None => true,
+        };
+
+        let status = GroupStatus {
+            global,
+            local: Status {
+                index: self.local_index,
+                last: group_last,
+                remaining: if group_last { Some(0) } else { None },
+            },
+            group_index: self.group_index,
+        };
+
+        if group_last {
+            self.local_index = 0;
+            self.group_index += 1;
+        } else {
+            self.local_index += 1;
+        }
+
+        Some((item, status))
+    }
+}
+
+impl<I> Clone for Grouper<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            local_index: self.local_index,
+            group_index: self.group_index,
+        }
+    }
+}
+
+impl<I> fmt::Debug for Grouper<I>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Grouper")
+            .field("iter", &self.iter)
+            .field("local_index", &self.local_index)
+            .field("group_index", &self.group_index)
+            .finish()
+    }
+}
+
+/// Iterator wrapper which yields every item together with its
+/// [`GroupStatus`], where groups consist of consecutive items with the same
+/// key. See
+/// [`IterStatusExt::with_group_status`][crate::IterStatusExt::with_group_status]
+/// for more information.
+pub struct WithGroupStatus<I: Iterator, F> {
+    grouper: Grouper<I>,
+    key: F,
+}
+
+impl<I: Iterator, F> WithGroupStatus<I, F> {
+    pub(crate) fn new(iter: I, key: F) -> Self {
+        Self {
+            grouper: Grouper::new(iter),
+            key,
+        }
+    }
+}
+
+impl<I, F> Clone for WithGroupStatus<I, F>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            grouper: self.grouper.clone(),
+            key: self.key.clone(),
+        }
+    }
+}
+
+impl<I, F> fmt::Debug for WithGroupStatus<I, F>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WithGroupStatus")
+            .field("grouper", &self.grouper)
+            .finish()
+    }
+}
+
+impl<I, F, K> Iterator for WithGroupStatus<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+    K: PartialEq,
+{
+    type Item = (I::Item, GroupStatus);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = &mut self.key;
+        self.grouper.next_by(|item, next| key(item) != key(next))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.grouper.iter.size_hint()
+    }
+}
+
+impl<I, F, K> FusedIterator for WithGroupStatus<I, F>
+where
+    I: FusedIterator,
+    F: FnMut(&I::Item) -> K,
+    K: PartialEq,
+{}
+
+impl<I, F, K> ExactSizeIterator for WithGroupStatus<I, F>
+where
+    I: ExactSizeIterator,
+    F: FnMut(&I::Item) -> K,
+    K: PartialEq,
+{
+    fn len(&self) -> usize {
+        self.grouper.iter.len()
+    }
+}
+
+/// The comparison function used by
+/// [`IterStatusExt::with_run_status`][crate::IterStatusExt::with_run_status],
+/// which is `PartialEq::eq`.
+pub type PartialEqFn<T> = fn(&T, &T) -> bool;
+
+/// Iterator wrapper which yields every item together with its
+/// [`GroupStatus`], where groups are runs of equal items. See
+/// [`IterStatusExt::with_run_status`][crate::IterStatusExt::with_run_status]
+/// for more information.
+pub struct WithRunStatus<I: Iterator, F> {
+    grouper: Grouper<I>,
+    eq: F,
+}
+
+impl<I: Iterator, F> WithRunStatus<I, F> {
+    pub(crate) fn new(iter: I, eq: F) -> Self {
+        Self {
+            grouper: Grouper::new(iter),
+            eq,
+        }
+    }
+}
+
+impl<I, F> Clone for WithRunStatus<I, F>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            grouper: self.grouper.clone(),
+            eq: self.eq.clone(),
+        }
+    }
+}
+
+impl<I, F> fmt::Debug for WithRunStatus<I, F>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WithRunStatus")
+            .field("grouper", &self.grouper)
+            .finish()
+    }
+}
+
+impl<I, F> Iterator for WithRunStatus<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    type Item = (I::Item, GroupStatus);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let eq = &mut self.eq;
+        self.grouper.next_by(|item, next| !eq(item, next))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.grouper.iter.size_hint()
+    }
+}
+
+impl<I, F> FusedIterator for WithRunStatus<I, F>
+where
+    I: FusedIterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{}
+
+impl<I, F> ExactSizeIterator for WithRunStatus<I, F>
+where
+    I: ExactSizeIterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    fn len(&self) -> usize {
+        self.grouper.iter.len()
+    }
+}
+
+/// Iterator wrapper which yields every item together with its
+/// [`GroupStatus`], where groups are separated by a custom predicate. See
+/// [`IterStatusExt::with_boundaries`][crate::IterStatusExt::with_boundaries]
+/// for more information.
+pub struct WithBoundaries<I: Iterator, F> {
+    grouper: Grouper<I>,
+    is_boundary: F,
+}
+
+impl<I: Iterator, F> WithBoundaries<I, F> {
+    pub(crate) fn new(iter: I, is_boundary: F) -> Self {
+        Self {
+            grouper: Grouper::new(iter),
+            is_boundary,
+        }
+    }
+}
+
+impl<I, F> Clone for WithBoundaries<I, F>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            grouper: self.grouper.clone(),
+            is_boundary: self.is_boundary.clone(),
+        }
+    }
+}
+
+impl<I, F> fmt::Debug for WithBoundaries<I, F>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WithBoundaries")
+            .field("grouper", &self.grouper)
+            .finish()
+    }
+}
+
+impl<I, F> Iterator for WithBoundaries<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    type Item = (I::Item, GroupStatus);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.grouper.next_by(&mut self.is_boundary)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.grouper.iter.size_hint()
+    }
+}
+
+impl<I, F> FusedIterator for WithBoundaries<I, F>
+where
+    I: FusedIterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{}
+
+impl<I, F> ExactSizeIterator for WithBoundaries<I, F>
+where
+    I: ExactSizeIterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    fn len(&self) -> usize {
+        self.grouper.iter.len()
+    }
+}
+
+/// Iterator adapter which drops delimiter items and yields all other items
+/// together with their [`GroupStatus`] within the segments between the
+/// delimiters. See
+/// [`IterStatusExt::split_when`][crate::IterStatusExt::split_when] for more
+/// information.
+pub struct SplitWhen<I: Iterator, P> {
+    iter: WithStatus<I>,
+    is_delimiter: P,
+
+    /// The index of the next item within its segment.
+    local_index: usize,
+
+    /// The index of the segment the next item belongs to.
+    segment_index: usize,
+}
+
+impl<I: Iterator, P> SplitWhen<I, P> {
+    pub(crate) fn new(iter: I, is_delimiter: P) -> Self {
+        Self {
+            iter: iter.with_status(),
+            is_delimiter,
+            local_index: 0,
+            segment_index: 0,
+        }
+    }
+}
+
+impl<I, P> Clone for SplitWhen<I, P>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    P: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            is_delimiter: self.is_delimiter.clone(),
+            local_index: self.local_index,
+            segment_index: self.segment_index,
+        }
+    }
+}
+
+impl<I, P> fmt::Debug for SplitWhen<I, P>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SplitWhen")
+            .field("iter", &self.iter)
+            .field("local_index", &self.local_index)
+            .field("segment_index", &self.segment_index)
+            .finish()
+    }
+}
+
+impl<I, P> Iterator for SplitWhen<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = (I::Item, GroupStatus);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (item, global) = loop {
+            let (item, global) = self.iter.next()?;
+            if !(self.is_delimiter)(&item) {
+                break (item, global);
+            }
+
+            self.local_index = 0;
+            self.segment_index += 1;
+        };
+
+        let segment_last = match self.iter.peek_item() {
+            Some(next) => (self.is_delimiter)(next),
+            None => true,
+        };
+        let status = GroupStatus {
+            global,
+            local: Status {
+                index: self.local_index,
+                last: segment_last,
+                remaining: if segment_last { Some(0) } else { None },
+            },
+            group_index: self.segment_index,
+        };
+        self.local_index += 1;
+
+        Some((item, status))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I, P> FusedIterator for SplitWhen<I, P>
+where
+    I: FusedIterator,
+    P: FnMut(&I::Item) -> bool,
+{}
+
+/// Iterator adapter which chains two iterators and yields every item together
+/// with its [`GroupStatus`], where the group is the source iterator. See
+/// [`IterStatusExt::chain_with_status`][crate::IterStatusExt::chain_with_status]
+/// for more information.
+pub struct ChainWithStatus<A: Iterator, B: Iterator> {
+    a: WithStatus<A>,
+    b: WithStatus<B>,
+
+    /// Whether `a` returned `None` already (possibly while looking ahead). It's
+    /// never polled again after that, even if it's not fused.
+    a_done: bool,
+
+    /// The index of the item that will be yielded next.
+    index: usize,
+}
+
+impl<A: Iterator, B: Iterator> ChainWithStatus<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        Self {
+            a: a.with_status(),
+            b: b.with_status(),
+            a_done: false,
+            index: 0,
+        }
+    }
+}
+
+impl<A, B> Clone for ChainWithStatus<A, B>
+where
+    A: Iterator + Clone,
+    A::Item: Clone,
+    B: Iterator + Clone,
+    B::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            a: self.a.clone(),
+            b: self.b.clone(),
+            a_done: self.a_done,
+            index: self.index,
+        }
+    }
+}
+
+impl<A, B> fmt::Debug for ChainWithStatus<A, B>
+where
+    A: Iterator + fmt::Debug,
+    A::Item: fmt::Debug,
+    B: Iterator + fmt::Debug,
+    B::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ChainWithStatus")
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .field("a_done", &self.a_done)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+impl<A, B> Iterator for ChainWithStatus<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+    type Item = (A::Item, GroupStatus);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let from_a = if self.a_done { None } else { self.a.next() };
+        let (item, local, group_index) = match from_a {
+            Some((item, local)) => {
+                // `a` already returned `None` when looking ahead.
+                self.a_done = local.last;
+                (item, local, 0)
+            }
+            None => {
+                self.a_done = true;
+                let (item, local) = self.b.next()?;
+                (item, local, 1)
+            }
+        };
+
+        // Items of the first iterator are followed by all items of the
+        // second one.
+        let (last, remaining) = if group_index == 0 {
+            let last = local.last && self.b.peek_item().is_none();
+            let remaining = match (local.remaining, exact_len(&self.b)) {
+                _ if last => Some(0),
+                (Some(a), Some(b)) => a.checked_add(b),
+                _ => None,
+            };
+            (last, remaining)
+        } else {
+            (local.last, local.remaining)
+        };
+
+        let status = GroupStatus {
+            global: Status {
+                index: self.index,
+                last,
+                remaining,
+            },
+            local,
+            group_index,
+        };
+        self.index += 1;
+
+        Some((item, status))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = if self.a_done { (0, Some(0)) } else { self.a.size_hint() };
+        let (b_lower, b_upper) = self.b.size_hint();
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _ => None,
+        };
+
+        (a_lower.saturating_add(b_lower), upper)
+    }
+}
+
+impl<A, B> FusedIterator for ChainWithStatus<A, B>
+where
+    A: Iterator,
+    B: FusedIterator<Item = A::Item>,
+{}
+
+/// The status of an item that belongs to a chunk of fixed size, yielded by
+/// [`WithChunkStatus`].
+///
+/// All chunks have the same size, except for the last one which might be
+/// smaller.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ChunkStatus {
+    global: Status,
+    chunk_size: usize,
+}
+
+impl ChunkStatus {
+    /// Returns the status of the item within the whole iterator.
+    pub const fn global(&self) -> Status {
+        self.global
+    }
+
+    /// Returns the zero-based index of the chunk this item belongs to.
+    pub const fn chunk_index(&self) -> usize {
+        self.global.index / self.chunk_size
+    }
+
+    /// Returns the zero-based index of this item within its chunk.
+    pub const fn index_in_chunk(&self) -> usize {
+        self.global.index % self.chunk_size
+    }
+
+    /// Returns `true` if this is the first item of its chunk.
+    pub const fn is_first_in_chunk(&self) -> bool {
+        self.index_in_chunk() == 0
+    }
+
+    /// Returns `true` if this is the last item of its chunk. This is also
+    /// the case for the last item of the iterator, even if its chunk is not
+    /// full.
+    pub const fn is_last_in_chunk(&self) -> bool {
+        self.index_in_chunk() == self.chunk_size - 1 || self.global.last
+    }
+}
+
+/// Iterator wrapper which yields every item together with its
+/// [`ChunkStatus`]. See
+/// [`IterStatusExt::with_chunk_status`][crate::IterStatusExt::with_chunk_status]
+/// for more information.
+pub struct WithChunkStatus<I: Iterator> {
+    iter: WithStatus<I>,
+    chunk_size: usize,
+}
+
+impl<I: Iterator> WithChunkStatus<I> {
+    pub(crate) fn new(iter: I, chunk_size: usize) -> Self {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+
+        Self {
+            iter: iter.with_status(),
+            chunk_size,
+        }
+    }
+}
+
+impl<I> Clone for WithChunkStatus<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            chunk_size: self.chunk_size,
+        }
+    }
+}
+
+impl<I> fmt::Debug for WithChunkStatus<I>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WithChunkStatus")
+            .field("iter", &self.iter)
+            .field("chunk_size", &self.chunk_size)
+            .finish()
+    }
+}
+
+impl<I: Iterator> Iterator for WithChunkStatus<I> {
+    type Item = (I::Item, ChunkStatus);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (item, global) = self.iter.next()?;
+        let status = ChunkStatus {
+            global,
+            chunk_size: self.chunk_size,
+        };
+
+        Some((item, status))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for WithChunkStatus<I> {}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for WithChunkStatus<I> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
@@ -0,0 +1,601 @@
+use std::{
+    cell::Cell,
+    fmt::{self, Write},
+    iter::FromIterator,
+};
+
+use crate::{IterStatusExt, ListFormat, SkipFirst, Status};
+
+/// Joins the `Display` representations of all items into a `String`, with
+/// `sep` between every two of them.
+///
+/// `sep` can be a simple string or any other [`ListFormat`].
+///
+/// # Example
+///
+/// ```
+/// let s = splop::join(&[1, 2, 3], ", ");
+/// assert_eq!(s, "1, 2, 3");
+///
+/// let empty: [u8; 0] = [];
+/// assert_eq!(splop::join(&empty, ", "), "");
+/// ```
+pub fn join<I, S>(iter: I, sep: S) -> String
+where
+    I: IntoIterator,
+    I::Item: fmt::Display,
+    S: ListFormat,
+{
+    let mut out = String::new();
+    for (item, status) in iter.into_iter().with_status() {
+        if !status.is_first() {
+            sep.write_separator(&mut out, status)
+                .expect("writing to a `String` never fails");
+        }
+        write!(out, "{}", item).expect("writing to a `String` never fails");
+    }
+
+    out
+}
+
+/// Creates a value that lazily joins the items with `sep` when it's
+/// formatted with `Display`, without allocating an intermediate `String`.
+///
+/// The iterator is cloned every time the returned value is formatted, so
+/// `iter` has to be cheaply clonable, like most iterators over references.
+/// Format options (like precision) are applied to every item.
+///
+/// # Example
+///
+/// ```
+/// let names = ["Peter", "Susan"];
+/// let s = format!("Hello {}!", splop::join_fmt(&names, " and "));
+/// assert_eq!(s, "Hello Peter and Susan!");
+///
+/// let s = format!("{:.1}", splop::join_fmt(&[1.0, 2.25], ", "));
+/// assert_eq!(s, "1.0, 2.2");
+/// ```
+pub fn join_fmt<'a, I, S>(iter: I, sep: S) -> DisplayJoined<'a, I::IntoIter, S>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+    I::Item: fmt::Display,
+    S: ListFormat,
+{
+    DisplayJoined::new(iter).sep(sep)
+}
+
+/// Lazily joins items with a separator when formatted with `Display`,
+/// optionally enclosed in brackets. Created by [`join_fmt`] or
+/// [`DisplayJoined::new`].
+///
+/// # Example
+///
+/// ```
+/// use splop::DisplayJoined;
+///
+/// let fruits = ["banana", "melon", "kiwi"];
+/// let s = DisplayJoined::new(&fruits).sep(", ").brackets("[", "]").to_string();
+/// assert_eq!(s, "[banana, melon, kiwi]");
+/// ```
+#[derive(Clone, Debug)]
+pub struct DisplayJoined<'a, I, S = &'a str> {
+    iter: I,
+    sep: S,
+    open: &'a str,
+    close: &'a str,
+}
+
+impl<'a, I: Iterator + Clone> DisplayJoined<'a, I> {
+    /// Creates a new instance joining the items of `iter` with `", "` and
+    /// without brackets.
+    ///
+    /// The iterator is cloned every time this value is formatted.
+    pub fn new<T>(iter: T) -> Self
+    where
+        T: IntoIterator<IntoIter = I>,
+    {
+        Self {
+            iter: iter.into_iter(),
+            sep: ", ",
+            open: "",
+            close: "",
+        }
+    }
+}
+
+impl<'a, I, S> DisplayJoined<'a, I, S> {
+    /// Sets the separator placed between every two items, which can be any
+    /// [`ListFormat`].
+    pub fn sep<T: ListFormat>(self, sep: T) -> DisplayJoined<'a, I, T> {
+        DisplayJoined {
+            iter: self.iter,
+            sep,
+            open: self.open,
+            close: self.close,
+        }
+    }
+
+    /// Sets the strings written before the first and after the last item.
+    /// They are written even if there are no items.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::DisplayJoined;
+    ///
+    /// let empty: [u8; 0] = [];
+    /// assert_eq!(DisplayJoined::new(&empty).brackets("{", "}").to_string(), "{}");
+    /// ```
+    pub fn brackets(mut self, open: &'a str, close: &'a str) -> Self {
+        self.open = open;
+        self.close = close;
+        self
+    }
+}
+
+impl<'a, I, S> fmt::Display for DisplayJoined<'a, I, S>
+where
+    I: Iterator + Clone,
+    I::Item: fmt::Display,
+    S: ListFormat,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_joined(f, self.iter.clone(), &self.sep, self.open, self.close, fmt::Display::fmt)
+    }
+}
+
+/// Like [`DisplayJoined`], but formats the items with `Debug` (`{:?}`)
+/// instead of `Display`.
+///
+/// # Example
+///
+/// ```
+/// use splop::DebugJoined;
+///
+/// let words = ["a", "b\"c"];
+/// let s = DebugJoined::new(&words).sep(" | ").brackets("<", ">").to_string();
+/// assert_eq!(s, r#"<"a" | "b\"c">"#);
+///
+/// let v = [Some(1), None];
+/// assert_eq!(format!("{}", DebugJoined::new(&v)), "Some(1), None");
+/// ```
+#[derive(Clone, Debug)]
+pub struct DebugJoined<'a, I, S = &'a str> {
+    iter: I,
+    sep: S,
+    open: &'a str,
+    close: &'a str,
+}
+
+impl<'a, I: Iterator + Clone> DebugJoined<'a, I> {
+    /// Creates a new instance joining the items of `iter` with `", "` and
+    /// without brackets.
+    ///
+    /// The iterator is cloned every time this value is formatted.
+    pub fn new<T>(iter: T) -> Self
+    where
+        T: IntoIterator<IntoIter = I>,
+    {
+        Self {
+            iter: iter.into_iter(),
+            sep: ", ",
+            open: "",
+            close: "",
+        }
+    }
+}
+
+impl<'a, I, S> DebugJoined<'a, I, S> {
+    /// Sets the separator placed between every two items, which can be any
+    /// [`ListFormat`].
+    pub fn sep<T: ListFormat>(self, sep: T) -> DebugJoined<'a, I, T> {
+        DebugJoined {
+            iter: self.iter,
+            sep,
+            open: self.open,
+            close: self.close,
+        }
+    }
+
+    /// Sets the strings written before the first and after the last item.
+    /// They are written even if there are no items.
+    pub fn brackets(mut self, open: &'a str, close: &'a str) -> Self {
+        self.open = open;
+        self.close = close;
+        self
+    }
+}
+
+impl<'a, I, S> fmt::Display for DebugJoined<'a, I, S>
+where
+    I: Iterator + Clone,
+    I::Item: fmt::Debug,
+    S: ListFormat,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_joined(f, self.iter.clone(), &self.sep, self.open, self.close, fmt::Debug::fmt)
+    }
+}
+
+/// Writes `open`, all items formatted with `fmt_item` and separated by `sep`,
+/// and `close`.
+fn write_joined<I, S, F>(
+    f: &mut fmt::Formatter,
+    iter: I,
+    sep: &S,
+    open: &str,
+    close: &str,
+    mut fmt_item: F,
+) -> fmt::Result
+where
+    I: Iterator,
+    S: ListFormat,
+    F: FnMut(&I::Item, &mut fmt::Formatter) -> fmt::Result,
+{
+    f.write_str(open)?;
+    for (item, status) in iter.with_status() {
+        if !status.is_first() {
+            sep.write_separator(f, status)?;
+        }
+        fmt_item(&item, f)?;
+    }
+    f.write_str(close)
+}
+
+/// Formats items with a custom closure and places a separator between them
+/// when formatted with `Display`. See
+/// [`IterStatusExt::format_with`][crate::IterStatusExt::format_with] for more
+/// information.
+pub struct FormatWith<I, S, F> {
+    sep: S,
+
+    /// The iterator and closure, which are taken when formatting.
+    inner: Cell<Option<(I, F)>>,
+}
+
+impl<I, S, F> FormatWith<I, S, F> {
+    pub(crate) fn new(iter: I, sep: S, f: F) -> Self {
+        Self {
+            sep,
+            inner: Cell::new(Some((iter, f))),
+        }
+    }
+}
+
+impl<I, S, F> fmt::Display for FormatWith<I, S, F>
+where
+    I: Iterator,
+    S: ListFormat,
+    F: FnMut(I::Item, &mut dyn FnMut(&dyn fmt::Display) -> fmt::Result) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (iter, mut format) = self.inner.take()
+            .expect("`FormatWith` was already formatted once");
+
+        for (item, status) in iter.with_status() {
+            if !status.is_first() {
+                self.sep.write_separator(f, status)?;
+            }
+            format(item, &mut |d| d.fmt(f))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<I, S: fmt::Debug, F> fmt::Debug for FormatWith<I, S, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FormatWith")
+            .field("sep", &self.sep)
+            .finish()
+    }
+}
+
+/// Joins items like a human would write a list: "a, b, and c". Also see
+/// [`human_join`].
+///
+/// By default, the conjunction is "and" and an Oxford comma is used (the
+/// comma before the conjunction). A list of two items never gets a comma:
+/// "a and b".
+///
+/// # Example
+///
+/// ```
+/// use splop::HumanJoin;
+///
+/// let or = HumanJoin::new().conjunction("or").oxford_comma(false);
+/// assert_eq!(or.join(&["tea", "coffee", "water"]), "tea, coffee or water");
+/// assert_eq!(or.join(&["tea", "coffee"]), "tea or coffee");
+/// assert_eq!(or.join(&["tea"]), "tea");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HumanJoin<'a> {
+    conjunction: &'a str,
+    oxford_comma: bool,
+}
+
+impl Default for HumanJoin<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> HumanJoin<'a> {
+    /// Creates a new instance using "and" and the Oxford comma.
+    pub const fn new() -> Self {
+        Self {
+            conjunction: "and",
+            oxford_comma: true,
+        }
+    }
+
+    /// Sets the word placed before the last item, e.g. "and" or "or".
+    pub const fn conjunction(mut self, conjunction: &'a str) -> Self {
+        self.conjunction = conjunction;
+        self
+    }
+
+    /// Sets whether to place a comma before the conjunction in lists of
+    /// three or more items.
+    pub const fn oxford_comma(mut self, oxford_comma: bool) -> Self {
+        self.oxford_comma = oxford_comma;
+        self
+    }
+
+    /// Joins the `Display` representations of all items into a `String`.
+    pub fn join<I>(&self, iter: I) -> String
+    where
+        I: IntoIterator,
+        I::Item: fmt::Display,
+    {
+        join(iter, self)
+    }
+}
+
+impl ListFormat for HumanJoin<'_> {
+    fn write_separator(&self, w: &mut dyn Write, next: Status) -> fmt::Result {
+        if !next.is_last() {
+            w.write_str(", ")
+        } else if next.index() >= 2 && self.oxford_comma {
+            write!(w, ", {} ", self.conjunction)
+        } else {
+            write!(w, " {} ", self.conjunction)
+        }
+    }
+}
+
+/// Joins items like a human would write a list: "a, b, and c". This is a
+/// shorthand for `HumanJoin::new().join(iter)`; use [`HumanJoin`] to
+/// configure the conjunction and the Oxford comma.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(splop::human_join(&["Peter", "Susan", "Tom"]), "Peter, Susan, and Tom");
+/// assert_eq!(splop::human_join(&["Peter", "Susan"]), "Peter and Susan");
+/// ```
+pub fn human_join<I>(iter: I) -> String
+where
+    I: IntoIterator,
+    I::Item: fmt::Display,
+{
+    HumanJoin::new().join(iter)
+}
+
+/// A configurable joiner: separator, a different separator before the last
+/// item, and a prefix and suffix around the whole list.
+///
+/// `Joiner` also implements [`ListFormat`], so it can be passed to all
+/// joining functions as separator (which ignores prefix and suffix).
+///
+/// # Example
+///
+/// ```
+/// use splop::Joiner;
+///
+/// let joiner = Joiner::new().sep(", ").last_sep(" and ").prefix("(").suffix(")");
+///
+/// assert_eq!(joiner.to_string(&["a", "b", "c"]), "(a, b and c)");
+/// assert_eq!(format!("list: {}", joiner.display(&[1, 2])), "list: (1 and 2)");
+///
+/// let mut s = String::new();
+/// joiner.write(&["x"], &mut s).unwrap();
+/// assert_eq!(s, "(x)");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Joiner<'a> {
+    sep: &'a str,
+    last_sep: Option<&'a str>,
+    prefix: &'a str,
+    suffix: &'a str,
+}
+
+impl Default for Joiner<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Joiner<'a> {
+    /// Creates a new joiner using `", "` as separator and no prefix or
+    /// suffix.
+    pub const fn new() -> Self {
+        Self {
+            sep: ", ",
+            last_sep: None,
+            prefix: "",
+            suffix: "",
+        }
+    }
+
+    /// Sets the separator placed between every two items.
+    pub const fn sep(mut self, sep: &'a str) -> Self {
+        self.sep = sep;
+        self
+    }
+
+    /// Sets the separator placed before the last item (if there are at least
+    /// two). By default, the normal separator is used.
+    pub const fn last_sep(mut self, last_sep: &'a str) -> Self {
+        self.last_sep = Some(last_sep);
+        self
+    }
+
+    /// Sets the string written before the first item. It's written even if
+    /// there are no items.
+    pub const fn prefix(mut self, prefix: &'a str) -> Self {
+        self.prefix = prefix;
+        self
+    }
+
+    /// Sets the string written after the last item. It's written even if
+    /// there are no items.
+    pub const fn suffix(mut self, suffix: &'a str) -> Self {
+        self.suffix = suffix;
+        self
+    }
+
+    /// Joins the `Display` representations of all items into a `String`.
+    pub fn to_string<I>(&self, iter: I) -> String
+    where
+        I: IntoIterator,
+        I::Item: fmt::Display,
+    {
+        let mut out = String::new();
+        self.write(iter, &mut out).expect("writing to a `String` never fails");
+        out
+    }
+
+    /// Returns a value that lazily joins the items when formatted with
+    /// `Display`. Like with [`join_fmt`], the iterator is cloned every time
+    /// the value is formatted.
+    pub fn display<I>(&self, iter: I) -> DisplayJoined<'_, I::IntoIter, &Self>
+    where
+        I: IntoIterator,
+        I::IntoIter: Clone,
+        I::Item: fmt::Display,
+    {
+        DisplayJoined::new(iter).sep(self).brackets(self.prefix, self.suffix)
+    }
+
+    /// Writes the `Display` representations of all items into `w`.
+    pub fn write<I, W>(&self, iter: I, w: &mut W) -> fmt::Result
+    where
+        I: IntoIterator,
+        I::Item: fmt::Display,
+        W: Write,
+    {
+        w.write_str(self.prefix)?;
+        for (item, status) in iter.into_iter().with_status() {
+            if !status.is_first() {
+                self.write_separator(w, status)?;
+            }
+            write!(w, "{}", item)?;
+        }
+        w.write_str(self.suffix)
+    }
+}
+
+impl ListFormat for Joiner<'_> {
+    fn write_separator(&self, w: &mut dyn Write, next: Status) -> fmt::Result {
+        match self.last_sep {
+            Some(last_sep) if next.is_last() => w.write_str(last_sep),
+            _ => w.write_str(self.sep),
+        }
+    }
+}
+
+/// A collection target that places a separator between all items written to
+/// it, e.g. via `Extend` or `collect`.
+///
+/// The items are written via their `Display` implementation into the
+/// string-like value `S` (anything implementing `fmt::Write`). Since items
+/// can be added one by one, the separator is a simple string and not a
+/// [`ListFormat`].
+///
+/// [`push`][Separated::push] reports errors of the underlying writer. The
+/// `Extend` implementation (and thus `collect`) panics on such errors
+/// instead, which never happens when writing to a `String`.
+///
+/// # Example
+///
+/// ```
+/// use splop::{IterStatusExt, Separated};
+///
+/// let mut s = Separated::<String>::with_sep(", ");
+/// s.extend(&[1, 2]);
+/// s.push(3).unwrap();
+/// assert_eq!(s.into_inner(), "1, 2, 3");
+///
+/// let s: Separated<String> = (1..4).map(|i| i * i).collect_with_sep(" + ");
+/// assert_eq!(s.to_string(), "1 + 4 + 9");
+///
+/// // `collect` uses ", " as separator
+/// let s: Separated<String> = ["a", "b"].iter().collect();
+/// assert_eq!(s.into_inner(), "a, b");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Separated<S = String> {
+    out: S,
+    sep: String,
+    gate: SkipFirst,
+}
+
+impl<S: Default> Separated<S> {
+    /// Creates a new, empty instance using `sep` as separator.
+    pub fn with_sep(sep: impl Into<String>) -> Self {
+        Self::new(S::default(), sep)
+    }
+}
+
+impl<S> Separated<S> {
+    /// Creates a new instance writing to `out` and using `sep` as separator.
+    /// If `out` is not empty, the first item is still not preceded by a
+    /// separator.
+    pub fn new(out: S, sep: impl Into<String>) -> Self {
+        Self {
+            out,
+            sep: sep.into(),
+            gate: SkipFirst::new(),
+        }
+    }
+
+    /// Returns the collected output.
+    pub fn into_inner(self) -> S {
+        self.out
+    }
+}
+
+impl<S: Write> Separated<S> {
+    /// Writes a single item, preceded by the separator if it's not the first
+    /// one. Returns the error of the underlying writer, if any.
+    pub fn push<T: fmt::Display>(&mut self, item: T) -> fmt::Result {
+        if self.gate.check() {
+            self.out.write_str(&self.sep)?;
+        }
+        write!(self.out, "{}", item)
+    }
+}
+
+impl<S: Write, T: fmt::Display> Extend<T> for Separated<S> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item).expect("writing to a `Separated` failed");
+        }
+    }
+}
+
+impl<S: Default + Write, T: fmt::Display> FromIterator<T> for Separated<S> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut out = Self::with_sep(", ");
+        out.extend(iter);
+        out
+    }
+}
+
+impl<S: fmt::Display> fmt::Display for Separated<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.out.fmt(f)
+    }
+}
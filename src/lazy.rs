@@ -0,0 +1,203 @@
+use std::{
+    cell::RefCell,
+    rc::Rc,
+};
+
+use crate::{exact_len, Position, Status};
+
+/// Iterator wrapper which determines the status of its items lazily. See
+/// [`IterStatusExt::with_status_lazy`][crate::IterStatusExt::with_status_lazy]
+/// for more information.
+pub struct WithStatusLazy<I: Iterator> {
+    state: Rc<RefCell<State<I>>>,
+}
+
+/// The state shared between the iterator and all statuses it handed out.
+struct State<I: Iterator> {
+    iter: I,
+
+    /// The item following the one that was yielded last. `None` means that we
+    /// haven't looked ahead yet, `Some(None)` means that the underlying
+    /// iterator is exhausted.
+    peeked: Option<Option<I::Item>>,
+
+    /// The number of items yielded so far.
+    yielded: usize,
+}
+
+impl<I: Iterator> WithStatusLazy<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            state: Rc::new(RefCell::new(State {
+                iter,
+                peeked: None,
+                yielded: 0,
+            })),
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for WithStatusLazy<I> {
+    type Item = (I::Item, LazyStatus<I>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut state = self.state.borrow_mut();
+
+        let item = match state.peeked.take() {
+            Some(peeked) => peeked,
+            None => state.iter.next(),
+        };
+
+        match item {
+            Some(item) => {
+                let status = LazyStatus {
+                    state: self.state.clone(),
+                    index: state.yielded,
+                };
+                state.yielded += 1;
+
+                Some((item, status))
+            }
+            None => {
+                // Remember that the iterator is exhausted to not call `next`
+                // on it again when a status is queried.
+                state.peeked = Some(None);
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let state = self.state.borrow();
+        let peeked = match state.peeked {
+            Some(Some(_)) => 1,
+            Some(None) => return (0, Some(0)),
+            None => 0,
+        };
+
+        let (lower, upper) = state.iter.size_hint();
+        (
+            lower.saturating_add(peeked),
+            upper.and_then(|upper| upper.checked_add(peeked)),
+        )
+    }
+}
+
+/// The status of an item yielded by [`WithStatusLazy`].
+///
+/// Whether or not the item is the last one is only determined when it's
+/// requested (by [`is_last`][LazyStatus::is_last] and all other methods that
+/// need this information). Only then the iterator fetches the next item.
+///
+/// A `LazyStatus` keeps working after the iterator was advanced further, but
+/// it shares state with the iterator, so it is neither `Send` nor `Sync`.
+pub struct LazyStatus<I: Iterator> {
+    state: Rc<RefCell<State<I>>>,
+    index: usize,
+}
+
+impl<I: Iterator> LazyStatus<I> {
+    /// Returns `true` if this is the first item of the iterator. This never
+    /// fetches another item. See [`Status::is_first`].
+    pub fn is_first(&self) -> bool {
+        self.index == 0
+    }
+
+    /// Returns `true` if this is the first item and it's not the only item in
+    /// the iterator. See [`Status::is_first_only`].
+    pub fn is_first_only(&self) -> bool {
+        self.is_first() && !self.is_last()
+    }
+
+    /// Returns `true` if this is the last item of the iterator. See
+    /// [`Status::is_last`].
+    ///
+    /// If the iterator hasn't been advanced since this item was yielded, this
+    /// method fetches the next item from the underlying iterator (only once).
+    pub fn is_last(&self) -> bool {
+        let mut state = self.state.borrow_mut();
+
+        // If another item was yielded after this one, we are clearly not the
+        // last one.
+        if state.yielded != self.index + 1 {
+            return false;
+        }
+
+        if state.peeked.is_none() {
+            state.peeked = Some(state.iter.next());
+        }
+        matches!(state.peeked, Some(None))
+    }
+
+    /// Returns `true` if this is the last item and it's not the only item in
+    /// the iterator. See [`Status::is_last_only`].
+    pub fn is_last_only(&self) -> bool {
+        self.is_last() && !self.is_first()
+    }
+
+    /// Returns `true` if this is neither the first nor the last item. See
+    /// [`Status::is_in_between`].
+    pub fn is_in_between(&self) -> bool {
+        !self.is_first() && !self.is_last()
+    }
+
+    /// Returns the zero-based index of this item. This never fetches another
+    /// item. See [`Status::index`].
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the position of this item. See [`Status::position`].
+    pub fn position(&self) -> Position {
+        self.to_status().position()
+    }
+
+    /// Resolves this lazy status into a normal [`Status`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let (_, status) = [27].iter()
+    ///     .with_status_lazy()
+    ///     .next()
+    ///     .unwrap();
+    ///
+    /// let status = status.to_status();
+    /// assert!(status.is_first());
+    /// assert!(status.is_last());
+    /// ```
+    pub fn to_status(&self) -> Status {
+        let last = self.is_last();
+
+        let remaining = if last {
+            Some(0)
+        } else {
+            // Items after this one are: the items already yielded after it,
+            // the buffered item and everything left in the iterator.
+            let state = self.state.borrow();
+            let yielded_after = state.yielded - self.index - 1;
+            match state.peeked {
+                Some(Some(_)) => exact_len(&state.iter).map(|len| len + 1 + yielded_after),
+                Some(None) => Some(yielded_after),
+                None => exact_len(&state.iter).map(|len| len + yielded_after),
+            }
+        };
+
+        Status {
+            index: self.index,
+            last,
+            remaining,
+        }
+    }
+}
+
+impl<I: Iterator> Clone for LazyStatus<I> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            index: self.index,
+        }
+    }
+}
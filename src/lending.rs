@@ -0,0 +1,139 @@
+use crate::Status;
+
+/// An iterator whose items may borrow from the iterator itself.
+///
+/// This is the lending (or "streaming") counterpart of `Iterator`: since an
+/// item may borrow from the iterator, you have to drop it before fetching the
+/// next one. That also means that it's impossible to look ahead, so
+/// [`LendingIterStatusExt::with_status`] requires an
+/// [`ExactSizeLendingIterator`].
+pub trait LendingIterator {
+    /// The type of the items, which may borrow from the iterator.
+    type Item<'a> where Self: 'a;
+
+    /// Advances the iterator and returns the next item.
+    fn next(&mut self) -> Option<Self::Item<'_>>;
+
+    /// Returns the bounds on the remaining length of the iterator, just like
+    /// `Iterator::size_hint`.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+/// A lending iterator that knows its exact length, like `ExactSizeIterator`.
+pub trait ExactSizeLendingIterator: LendingIterator {
+    /// Returns the exact remaining length of the iterator.
+    ///
+    /// The default implementation uses [`LendingIterator::size_hint`] and
+    /// panics if it is not exact.
+    fn len(&self) -> usize {
+        let (lower, upper) = self.size_hint();
+        assert_eq!(upper, Some(lower));
+        lower
+    }
+
+    /// Returns `true` if the iterator is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Lending iterator wrapper which keeps track of the status. See
+/// [`LendingIterStatusExt::with_status`] for more information.
+#[derive(Clone, Debug)]
+pub struct LendingWithStatus<L> {
+    iter: L,
+
+    /// The index of the item that will be yielded next.
+    index: usize,
+}
+
+impl<L: ExactSizeLendingIterator> LendingIterator for LendingWithStatus<L> {
+    type Item<'a> = (L::Item<'a>, Status) where Self: 'a;
+
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        // We can't look ahead, so we determine the status by counting. The
+        // length has to be queried before, as the item borrows the iterator.
+        let remaining = self.iter.len().saturating_sub(1);
+        let item = self.iter.next()?;
+
+        let status = Status {
+            index: self.index,
+            last: remaining == 0,
+            remaining: Some(remaining),
+        };
+        self.index += 1;
+
+        Some((item, status))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<L: ExactSizeLendingIterator> ExactSizeLendingIterator for LendingWithStatus<L> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+/// Adds the `with_status` method to all lending iterators with known length.
+pub trait LendingIterStatusExt: ExactSizeLendingIterator + Sized {
+    /// Creates a lending iterator that yields the original items paired with
+    /// a [`Status`], just like [`IterStatusExt::with_status`][crate::IterStatusExt::with_status]
+    /// does for iterators.
+    ///
+    /// The items can't be buffered, so the status is determined by counting
+    /// (like [`IterStatusExt::with_status_counted`][crate::IterStatusExt::with_status_counted]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::{ExactSizeLendingIterator, LendingIterator, LendingIterStatusExt};
+    ///
+    /// /// Yields overlapping mutable windows of length 2.
+    /// struct PairsMut<'s> {
+    ///     slice: &'s mut [u32],
+    ///     pos: usize,
+    /// }
+    ///
+    /// impl<'s> LendingIterator for PairsMut<'s> {
+    ///     type Item<'a> = &'a mut [u32] where Self: 'a;
+    ///
+    ///     fn next(&mut self) -> Option<Self::Item<'_>> {
+    ///         let window = self.slice.get_mut(self.pos..self.pos + 2)?;
+    ///         self.pos += 1;
+    ///         Some(window)
+    ///     }
+    ///
+    ///     fn size_hint(&self) -> (usize, Option<usize>) {
+    ///         let len = (self.slice.len() - self.pos).saturating_sub(1);
+    ///         (len, Some(len))
+    ///     }
+    /// }
+    ///
+    /// impl<'s> ExactSizeLendingIterator for PairsMut<'s> {}
+    ///
+    /// let mut numbers = [1, 2, 3, 4];
+    /// let mut iter = PairsMut { slice: &mut numbers, pos: 0 }.with_status();
+    /// while let Some((window, status)) = iter.next() {
+    ///     if !status.is_last() {
+    ///         window[1] += window[0];
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(numbers, [1, 3, 6, 4]);
+    /// ```
+    fn with_status(self) -> LendingWithStatus<Self>;
+}
+
+impl<L: ExactSizeLendingIterator> LendingIterStatusExt for L {
+    fn with_status(self) -> LendingWithStatus<Self> {
+        LendingWithStatus {
+            iter: self,
+            index: 0,
+        }
+    }
+}
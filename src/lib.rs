@@ -6,10 +6,137 @@
 //!   tell you if this is the first/last item.
 //! - [`SkipFirst`]: a simple struct to help you always do something, except on
 //!   the first repetition. Works without iterators, too!
+//!
+//!
+//! # Cargo features
+//!
+//! All of the following features are disabled by default.
+//!
+//! - `fallible-iterator`: adds `FallibleIterStatusExt` which offers
+//!   `with_status` for fallible iterators.
+//! - `itertools`: conversions between [`Status`]/[`Position`] and
+//!   `itertools::Position`.
+//! - `log`: adds the `log_once!` and `warn_once!` macros.
+//! - `rayon`: adds `ParIterStatusExt` which offers `with_status` for indexed
+//!   parallel iterators.
+//! - `serde`: implements `Serialize` and `Deserialize` for [`Status`] and
+//!   [`Position`]. A `Status` is serialized with explicit `first` and `last`
+//!   flags; inconsistent input is rejected when deserializing.
+//!
+//!   ```
+//!   # #[cfg(feature = "serde")]
+//!   # fn main() {
+//!   extern crate serde_json;
+//!   # extern crate splop;
+//!   use splop::IterStatusExt;
+//!
+//!   let (_, status) = [1, 2].iter().with_status().next().unwrap();
+//!   let json = serde_json::to_string(&status).unwrap();
+//!   assert_eq!(json, r#"{"index":0,"first":true,"last":false,"remaining":1}"#);
+//!
+//!   // Inconsistent statuses are rejected.
+//!   let json = r#"{"index":3,"first":true,"last":false,"remaining":1}"#;
+//!   assert!(serde_json::from_str::<splop::Status>(json).is_err());
+//!   # }
+//!   # #[cfg(not(feature = "serde"))]
+//!   # fn main() {}
+//!   ```
+//! - `tokio-stream`: adds `StreamStatusExt` which offers `with_status` for
+//!   streams.
+//! - `tracing`: adds `IterStatusExt::with_status_traced` which emits
+//!   `tracing` events for the first and last item.
 
 use std::{
-    iter::{FusedIterator, Peekable},
+    fmt,
+    iter::FusedIterator,
+    ops::ControlFlow,
+};
+
+#[cfg(feature = "tokio-stream")]
+#[macro_use]
+extern crate pin_project_lite;
+#[cfg(feature = "fallible-iterator")]
+extern crate fallible_iterator;
+#[cfg(feature = "itertools")]
+extern crate itertools;
+#[cfg(feature = "log")]
+extern crate log;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "tokio-stream")]
+extern crate tokio_stream;
+#[cfg(feature = "tracing")]
+extern crate tracing;
+
+mod adapters;
+mod counted;
+#[cfg(feature = "fallible-iterator")]
+mod fallible;
+mod format;
+mod gates;
+mod group;
+mod join;
+mod lazy;
+mod lending;
+mod lookahead;
+mod macros;
+#[cfg(feature = "rayon")]
+mod par;
+mod separator;
+#[cfg(feature = "tokio-stream")]
+mod stream;
+#[cfg(feature = "tracing")]
+mod traced;
+mod tracker;
+mod tree;
+mod write;
+mod zip;
+
+pub use adapters::{
+    AfterLast, BeforeFirst, Delimit, Element, FilterWithStatus, InspectWithStatus,
+    IntersperseWith, MapFirst, MapInBetween, MapLast, MapWithStatus, ScanWithStatus, SkipLast,
+    SkipLastN, Surround, TapFirst, TapLast, TerminateWith, Token, WindowsWithStatus,
+    WithNeighbors,
+};
+pub use counted::WithStatusCounted;
+#[cfg(feature = "fallible-iterator")]
+pub use fallible::{FallibleIterStatusExt, FallibleWithStatus};
+pub use format::{CommaList, GermanUnd, ListFormat, SemicolonList};
+pub use gates::{
+    AfterN, Alternator, AtomicSkipFirst, EveryNth, NthTime, RunAtMost, RunFirst, SkipFirstCell,
+    SkipFirstN, Toggle,
 };
+pub use group::{
+    ChainWithStatus, ChunkStatus, GroupStatus, PartialEqFn, SplitWhen, WithBoundaries,
+    WithChunkStatus, WithGroupStatus, WithRunStatus,
+};
+pub use join::{
+    human_join, join, join_fmt, DebugJoined, DisplayJoined, FormatWith, HumanJoin, Joiner,
+    Separated,
+};
+pub use lazy::{LazyStatus, WithStatusLazy};
+pub use lending::{
+    ExactSizeLendingIterator,
+    LendingIterator,
+    LendingIterStatusExt,
+    LendingWithStatus,
+};
+pub use lookahead::WithLookahead;
+#[doc(hidden)]
+pub use macros::__private;
+#[cfg(feature = "rayon")]
+pub use par::{ParIterStatusExt, ParWithStatus};
+pub use separator::{once_then, DisplaySep, OnceThen, Separator};
+#[cfg(feature = "tokio-stream")]
+pub use stream::{StreamStatusExt, StreamWithStatus};
+#[cfg(feature = "tracing")]
+pub use traced::WithStatusTraced;
+pub use tracker::{LoopStatus, StatusTracker};
+pub use tree::{TreeMarkers, TreePrefixes, TreeTracker};
+pub use write::{FmtWriteSeparated, ListBuilder, WriteSeparated};
+pub use zip::{Exhausted, ZipStatus, ZipWithStatus};
 
 /// Allows you to always do something, except the first time.
 ///
@@ -35,13 +162,32 @@ use std::{
 ///
 /// // Printed "peter, ingrid, barbara"
 /// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct SkipFirst {
     first: bool,
 }
 
+impl Default for SkipFirst {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl SkipFirst {
     /// Creates a new instance of `SkipFirst`.
-    pub fn new() -> Self {
+    ///
+    /// This is a `const fn`, so you can also use it to initialize constants
+    /// and statics:
+    ///
+    /// ```
+    /// use splop::SkipFirst;
+    ///
+    /// const SKIPPER: SkipFirst = SkipFirst::new();
+    ///
+    /// let mut skipper = SKIPPER;
+    /// assert_eq!(skipper.skip_first(|| 1), None);
+    /// ```
+    pub const fn new() -> Self {
         Self {
             first: true,
         }
@@ -80,28 +226,407 @@ impl SkipFirst {
     ///
     /// assert_eq!(v, ["b2", "a2"]);
     /// ```
+    ///
+    /// The value returned by the closure is passed through, so `None` is
+    /// returned the first time and `Some` with the closure's result
+    /// afterwards:
+    ///
+    /// ```
+    /// use splop::SkipFirst;
+    ///
+    /// let mut sep = SkipFirst::new();
+    /// let v: Vec<_> = ["a", "b", "c"].iter()
+    ///     .map(|s| format!("{}{}", sep.skip_first(|| "-").unwrap_or(""), s))
+    ///     .collect();
+    ///
+    /// assert_eq!(v, ["a", "-b", "-c"]);
+    /// ```
     pub fn skip_first<R>(&mut self, f: impl FnOnce() -> R) -> Option<R> {
-        if self.first {
-            self.first = false;
+        if self.check() {
+            Some(f())
+        } else {
             None
+        }
+    }
+
+    /// Executes `on_first` the first time this method is called on this
+    /// instance and `on_rest` on every later call. Returns the value returned
+    /// by the executed function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::SkipFirst;
+    ///
+    /// let mut s = String::new();
+    /// let mut gate = SkipFirst::new();
+    /// for name in &["peter", "ingrid"] {
+    ///     let prefix = gate.skip_first_or_else(|| "Names: ", || ", ");
+    ///     s.push_str(prefix);
+    ///     s.push_str(name);
+    /// }
+    ///
+    /// assert_eq!(s, "Names: peter, ingrid");
+    /// ```
+    pub fn skip_first_or_else<R>(
+        &mut self,
+        on_first: impl FnOnce() -> R,
+        on_rest: impl FnOnce() -> R,
+    ) -> R {
+        if self.check() {
+            on_rest()
         } else {
-            Some(f())
+            on_first()
         }
     }
+
+    /// Like [`skip_first`][SkipFirst::skip_first], but for functions that
+    /// can fail: an error returned by `f` is passed through, a successful
+    /// result is returned as `Ok(Some(_))`. The first time, `Ok(None)` is
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::io::{self, Write};
+    /// use splop::SkipFirst;
+    ///
+    /// fn write_names(w: &mut impl Write, names: &[&str]) -> io::Result<()> {
+    ///     let mut comma = SkipFirst::new();
+    ///     for name in names {
+    ///         comma.try_skip_first(|| w.write_all(b", "))?;
+    ///         w.write_all(name.as_bytes())?;
+    ///     }
+    ///     Ok(())
+    /// }
+    ///
+    /// let mut out = Vec::new();
+    /// write_names(&mut out, &["peter", "ingrid"]).unwrap();
+    /// assert_eq!(out, b"peter, ingrid");
+    /// ```
+    pub fn try_skip_first<R, E>(
+        &mut self,
+        f: impl FnOnce() -> Result<R, E>,
+    ) -> Result<Option<R>, E> {
+        self.skip_first(f).transpose()
+    }
+
+    /// Returns `false` the first time this method is called on this instance
+    /// and `true` on every later call.
+    ///
+    /// This is the same as [`skip_first`][SkipFirst::skip_first], but without
+    /// a closure. That's useful if the code that should be skipped needs
+    /// mutable access to variables that are also used outside of it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::SkipFirst;
+    ///
+    /// let mut s = String::new();
+    /// let mut comma = SkipFirst::new();
+    /// for name in &["peter", "ingrid", "barbara"] {
+    ///     if comma.check() {
+    ///         s.push_str(", ");
+    ///     }
+    ///     s.push_str(name);
+    /// }
+    ///
+    /// assert_eq!(s, "peter, ingrid, barbara");
+    /// ```
+    pub fn check(&mut self) -> bool {
+        let first = self.first;
+        self.first = false;
+        !first
+    }
+
+    /// Returns `true` if the first call (which was skipped) already happened.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::SkipFirst;
+    ///
+    /// let mut skipper = SkipFirst::new();
+    /// assert!(!skipper.has_skipped());
+    ///
+    /// skipper.skip_first(|| ());
+    /// assert!(skipper.has_skipped());
+    /// ```
+    pub const fn has_skipped(&self) -> bool {
+        !self.first
+    }
+
+    /// Resets this instance to its initial state, so that the next call is
+    /// skipped again. This allows reusing one instance for multiple passes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::SkipFirst;
+    ///
+    /// let mut comma = SkipFirst::new();
+    /// let mut lines = Vec::new();
+    /// for row in &[["a", "b"], ["c", "d"]] {
+    ///     comma.reset();
+    ///     let mut line = String::new();
+    ///     for cell in row {
+    ///         comma.skip_first(|| line.push_str(", "));
+    ///         line.push_str(cell);
+    ///     }
+    ///     lines.push(line);
+    /// }
+    ///
+    /// assert_eq!(lines, ["a, b", "c, d"]);
+    /// ```
+    pub fn reset(&mut self) {
+        self.first = true;
+    }
+
+    /// Consumes this instance and returns the updated instance together with
+    /// the result of [`check`][SkipFirst::check], i.e. whether the action
+    /// should run.
+    ///
+    /// This is useful if the instance lives in an accumulator, e.g. in
+    /// `fold`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::SkipFirst;
+    ///
+    /// let (s, _) = ["peter", "ingrid", "barbara"].iter()
+    ///     .fold((String::new(), SkipFirst::new()), |(mut s, gate), name| {
+    ///         let (gate, should_run) = gate.step();
+    ///         if should_run {
+    ///             s.push_str(", ");
+    ///         }
+    ///         s.push_str(name);
+    ///         (s, gate)
+    ///     });
+    ///
+    /// assert_eq!(s, "peter, ingrid, barbara");
+    /// ```
+    pub fn step(mut self) -> (Self, bool) {
+        let should_run = self.check();
+        (self, should_run)
+    }
+
+    /// Consumes this instance and returns it as if the first call already
+    /// happened.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::SkipFirst;
+    ///
+    /// let gate = SkipFirst::new().advance();
+    /// assert!(gate.has_skipped());
+    ///
+    /// let (_, should_run) = gate.step();
+    /// assert!(should_run);
+    /// ```
+    pub fn advance(self) -> Self {
+        self.step().0
+    }
 }
 
 /// Iterator wrapper which keeps track of the status. See
 /// [`IterStatusExt::with_status`] for more information.
 pub struct WithStatus<I: Iterator> {
-    iter: Peekable<I>,
-    first: bool,
+    iter: I,
+
+    /// The item following the one that was yielded last. This is always
+    /// filled by `next` (if there is another item), since we need to know
+    /// whether an item is the last one before yielding it.
+    peeked: Option<I::Item>,
+
+    /// The item following `peeked`. This is only filled by `peek`, which
+    /// needs to know whether the peeked item is the last one.
+    peeked_next: Option<I::Item>,
+
+    /// The index of the item that will be yielded next.
+    index: usize,
+
+    /// The number of items already yielded by `next_back`.
+    back: usize,
 }
 
 impl<I: Iterator> WithStatus<I> {
     fn new(iter: I) -> Self {
         Self {
-            iter: iter.peekable(),
-            first: true,
+            iter,
+            peeked: None,
+            peeked_next: None,
+            index: 0,
+            back: 0,
+        }
+    }
+
+    /// Returns a reference to the next item and its status, without
+    /// advancing the iterator.
+    ///
+    /// To know whether the next item is the last one, this might fetch up to
+    /// two items from the underlying iterator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let mut iter = ["a", "b"].iter().with_status();
+    ///
+    /// let (item, status) = iter.peek().unwrap();
+    /// assert_eq!(**item, "a");
+    /// assert!(status.is_first_only());
+    ///
+    /// iter.next();
+    /// let (item, status) = iter.peek().unwrap();
+    /// assert_eq!(**item, "b");
+    /// assert!(status.is_last_only());
+    ///
+    /// iter.next();
+    /// assert!(iter.peek().is_none());
+    /// ```
+    pub fn peek(&mut self) -> Option<(&I::Item, Status)> {
+        if self.peeked.is_none() {
+            self.peeked = self.fetch();
+        }
+        if self.peeked.is_some() && self.peeked_next.is_none() {
+            self.peeked_next = self.iter.next();
+        }
+
+        let last = self.peeked_next.is_none() && self.back == 0;
+        let status = Status {
+            index: self.index,
+            last,
+            remaining: if last {
+                Some(0)
+            } else {
+                // The length includes the peeked item itself.
+                exact_len(self).map(|len| len - 1 + self.back)
+            },
+        };
+
+        self.peeked.as_ref().map(|item| (item, status))
+    }
+
+    /// Returns the underlying iterator and the items which were already
+    /// fetched from it, but not yet yielded.
+    ///
+    /// Since `WithStatus` fetches items in advance, the underlying iterator
+    /// might already be advanced by one (or two, after calling
+    /// [`peek`][WithStatus::peek]). Chain the buffered items with the
+    /// iterator to not lose any items.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let mut iter = (0..5).with_status();
+    /// iter.next();
+    ///
+    /// let (rest, buffered) = iter.into_inner();
+    /// assert_eq!(buffered.len(), 1);
+    ///
+    /// let rest: Vec<_> = buffered.chain(rest).collect();
+    /// assert_eq!(rest, [1, 2, 3, 4]);
+    /// ```
+    pub fn into_inner(self) -> (I, Buffered<I::Item>) {
+        let buffered = Buffered {
+            first: self.peeked,
+            second: self.peeked_next,
+        };
+
+        (self.iter, buffered)
+    }
+
+    /// Returns the item that will be yielded next, without its status.
+    ///
+    /// In contrast to [`peek`][WithStatus::peek], this never reads further
+    /// ahead than `next` does: after `next` was called, this only returns the
+    /// already buffered item. Before that, it fetches at most one item.
+    pub(crate) fn peek_item(&mut self) -> Option<&I::Item> {
+        if self.peeked.is_none() && self.index == 0 && self.back == 0 {
+            self.peeked = self.fetch();
+        }
+        self.peeked.as_ref()
+    }
+
+    /// Returns the number of items in our buffer.
+    fn buffered_len(&self) -> usize {
+        self.peeked.is_some() as usize + self.peeked_next.is_some() as usize
+    }
+
+    /// Fetches the item after `peeked`.
+    fn fetch(&mut self) -> Option<I::Item> {
+        match self.peeked_next.take() {
+            Some(item) => Some(item),
+            None => self.iter.next(),
+        }
+    }
+
+    /// Like `try_fold`, but with `ControlFlow` instead of a generic `Try`
+    /// type, which is still unstable. The short-circuiting methods of
+    /// `Iterator` are implemented via this to pass through to `try_fold` of
+    /// the underlying iterator.
+    fn try_fold_flow<B, R, F>(&mut self, init: B, mut f: F) -> ControlFlow<R, B>
+    where
+        F: FnMut(B, (I::Item, Status)) -> ControlFlow<R, B>,
+    {
+        let mut left = exact_len(self);
+        let index = &mut self.index;
+        let back = self.back;
+
+        // Just like in `fold`, we always hold back one item until we know
+        // whether another item follows.
+        let mut step = |(acc, pending): (B, Option<I::Item>), item| {
+            let pending = match pending {
+                Some(pending) => pending,
+                None => return ControlFlow::Continue((acc, Some(item))),
+            };
+
+            left = left.map(|left| left.saturating_sub(1));
+            let status = Status {
+                index: *index,
+                last: false,
+                remaining: left.map(|left| left + back),
+            };
+            *index += 1;
+
+            match f(acc, (pending, status)) {
+                ControlFlow::Continue(acc) => ControlFlow::Continue((acc, Some(item))),
+                ControlFlow::Break(r) => ControlFlow::Break((r, item)),
+            }
+        };
+
+        let state = (init, self.peeked.take());
+        let res = match self.peeked_next.take() {
+            Some(item) => match step(state, item) {
+                ControlFlow::Continue(state) => self.iter.try_fold(state, &mut step),
+                ControlFlow::Break(b) => ControlFlow::Break(b),
+            },
+            None => self.iter.try_fold(state, &mut step),
+        };
+
+        match res {
+            // We stopped early, so we have to put the item we fetched in
+            // advance into our buffer.
+            ControlFlow::Break((r, item)) => {
+                self.peeked = Some(item);
+                ControlFlow::Break(r)
+            }
+            ControlFlow::Continue((acc, Some(pending))) => {
+                let status = Status {
+                    index: self.index,
+                    last: self.back == 0,
+                    remaining: Some(self.back),
+                };
+                self.index += 1;
+                f(acc, (pending, status))
+            }
+            ControlFlow::Continue((acc, None)) => ControlFlow::Continue(acc),
         }
     }
 }
@@ -110,84 +635,1842 @@ impl<I: Iterator> Iterator for WithStatus<I> {
     type Item = (I::Item, Status);
 
     fn next(&mut self) -> Option<Self::Item> {
-        // Get the next item from the iterator.
-        let item = self.iter.next();
+        // Get the next item, either from our buffer or from the iterator.
+        let item = match self.peeked.take() {
+            Some(item) => item,
+            None => self.fetch()?,
+        };
 
+        // Now we can check if there is still another item. Items already
+        // yielded from the back still come after this item, of course.
+        self.peeked = self.fetch();
+        let last = self.peeked.is_none() && self.back == 0;
         let status = Status {
-            first: self.first,
-            // Since we already got the real item above, we can now peek if
-            // there is still another item.
-            last: self.iter.peek().is_none(),
+            index: self.index,
+            last,
+            remaining: if last {
+                Some(0)
+            } else {
+                exact_len(self).map(|len| len + self.back)
+            },
         };
 
-        if self.first {
-            self.first = false;
+        self.index += 1;
+        Some((item, status))
+    }
+
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        // Instead of peeking, we always hold back one item until we know
+        // whether another item follows.
+        let mut left = exact_len(&self);
+        let WithStatus { iter, peeked, peeked_next, mut index, back } = self;
+
+        let items = peeked_next.into_iter().chain(iter);
+        let (acc, pending) = items.fold((init, peeked), |(acc, pending), item| {
+            let acc = match pending {
+                Some(pending) => {
+                    left = left.map(|left| left.saturating_sub(1));
+                    let status = Status {
+                        index,
+                        last: false,
+                        remaining: left.map(|left| left + back),
+                    };
+                    index += 1;
+                    f(acc, (pending, status))
+                }
+                None => acc,
+            };
+
+            (acc, Some(item))
+        });
+
+        // The item we held back last is followed only by the items already
+        // yielded from the back.
+        match pending {
+            Some(pending) => {
+                let status = Status {
+                    index,
+                    last: back == 0,
+                    remaining: Some(back),
+                };
+                f(acc, (pending, status))
+            }
+            None => acc,
+        }
+    }
+
+    fn for_each<F>(self, mut f: F)
+    where
+        F: FnMut(Self::Item),
+    {
+        self.fold((), move |(), item| f(item));
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n > 0 {
+            // Skip `n` items: the buffered ones (if any) and the rest from
+            // the underlying iterator.
+            let mut skip = n;
+            while skip > 0 && self.peeked.is_some() {
+                self.peeked = self.peeked_next.take();
+                skip -= 1;
+            }
+            if skip > 0 {
+                self.iter.nth(skip - 1)?;
+            }
+
+            self.index += n;
+        }
+
+        self.next()
+    }
+
+    fn count(self) -> usize {
+        self.buffered_len() + self.iter.count()
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        match exact_len(&self) {
+            // If we know how many items are left, we know the index of the
+            // last one and can use `last` of the underlying iterator.
+            Some(0) => None,
+            Some(len) => {
+                let status = Status {
+                    index: self.index + len - 1,
+                    last: self.back == 0,
+                    remaining: Some(self.back),
+                };
+                self.iter.last()
+                    .or(self.peeked_next)
+                    .or(self.peeked)
+                    .map(|item| (item, status))
+            }
+            None => self.fold(None, |_, item| Some(item)),
+        }
+    }
+
+    // `try_fold` itself can't be overridden on stable Rust, as the `Try` trait
+    // is unstable. So instead, we override the methods that use `try_fold`
+    // by default.
+
+    fn any<F>(&mut self, mut f: F) -> bool
+    where
+        F: FnMut(Self::Item) -> bool,
+    {
+        self.try_fold_flow((), |(), item| {
+            if f(item) {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        }).is_break()
+    }
+
+    fn all<F>(&mut self, mut f: F) -> bool
+    where
+        F: FnMut(Self::Item) -> bool,
+    {
+        self.try_fold_flow((), |(), item| {
+            if f(item) {
+                ControlFlow::Continue(())
+            } else {
+                ControlFlow::Break(())
+            }
+        }).is_continue()
+    }
+
+    fn find<P>(&mut self, mut predicate: P) -> Option<Self::Item>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        let res = self.try_fold_flow((), |(), item| {
+            if predicate(&item) {
+                ControlFlow::Break(item)
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+
+        match res {
+            ControlFlow::Break(item) => Some(item),
+            ControlFlow::Continue(()) => None,
         }
+    }
+
+    fn find_map<B, F>(&mut self, mut f: F) -> Option<B>
+    where
+        F: FnMut(Self::Item) -> Option<B>,
+    {
+        let res = self.try_fold_flow((), |(), item| match f(item) {
+            Some(b) => ControlFlow::Break(b),
+            None => ControlFlow::Continue(()),
+        });
 
-        item.map(|elem| (elem, status))
+        match res {
+            ControlFlow::Break(b) => Some(b),
+            ControlFlow::Continue(()) => None,
+        }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
         // We pass through the `size_hint` method, as the underlying iterator
-        // might have size information.
-        self.iter.size_hint()
+        // might have size information. We just have to account for the
+        // buffered items.
+        let buffered = self.buffered_len();
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.saturating_add(buffered),
+            upper.and_then(|upper| upper.checked_add(buffered)),
+        )
     }
 }
 
-// Implement traits when the underlying iterator implements them.
-impl<I: FusedIterator> FusedIterator for WithStatus<I> {}
-impl<I: ExactSizeIterator> ExactSizeIterator for WithStatus<I> {
-    fn len(&self) -> usize {
-        self.iter.len()
+/// Cloning requires the item type to be `Clone` as well, since `WithStatus`
+/// might have buffered an item.
+///
+/// # Example
+///
+/// ```
+/// use splop::IterStatusExt;
+///
+/// let mut iter = ["a", "b", "c"].iter().with_status();
+/// iter.next();
+///
+/// let lookahead: Vec<_> = iter.clone().map(|(s, _)| *s).collect();
+/// assert_eq!(lookahead, ["b", "c"]);
+///
+/// let (_, status) = iter.next().unwrap();
+/// assert_eq!(status.index(), 1);
+/// ```
+impl<I> Clone for WithStatus<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            peeked: self.peeked.clone(),
+            peeked_next: self.peeked_next.clone(),
+            index: self.index,
+            back: self.back,
+        }
     }
 }
 
-/// Adds the `with_status` method to all iterators.
-pub trait IterStatusExt: Iterator + Sized {
-    /// Creates an iterator that yields the original items paired with a
-    /// status, which tells you if the item is the first and/or last one.
-    ///
-    /// The new iterator's item has the type `(Self::Item, Status)`. See
-    /// [`Status`] for detailed information. The new iterator uses `peekable()`
-    /// internally, so if the `next()` call of the underlying iterator has
-    /// side effects, those will be visible earlier than expected.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// use splop::IterStatusExt;
-    ///
-    ///
-    /// let mut s = String::new();
-    /// let names = ["anna", "peter", "bob"];
-    ///
-    /// for (name, status) in names.iter().with_status() {
-    ///     if !status.is_first() {
-    ///         s += ", ";
-    ///     }
-    ///
-    ///     s += name;
-    /// }
-    ///
-    /// assert_eq!(s, "anna, peter, bob");
-    /// ```
-    fn with_status(self) -> WithStatus<Self>;
+/// # Example
+///
+/// ```
+/// use splop::IterStatusExt;
+///
+/// let mut iter = [1, 2].iter().with_status();
+/// iter.next();
+///
+/// let s = format!("{:?}", iter);
+/// assert!(s.contains("peeked: Some(2)"));
+/// assert!(s.contains("index: 1"));
+/// ```
+impl<I> fmt::Debug for WithStatus<I>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WithStatus")
+            .field("iter", &self.iter)
+            .field("peeked", &self.peeked)
+            .field("peeked_next", &self.peeked_next)
+            .field("index", &self.index)
+            .field("back", &self.back)
+            .finish()
+    }
 }
 
-impl<I: Iterator> IterStatusExt for I {
-    fn with_status(self) -> WithStatus<Self> {
+/// Iterating from the back requires the underlying iterator to be an
+/// `ExactSizeIterator`, as otherwise the index of the items would be unknown.
+///
+/// # Example
+///
+/// ```
+/// use splop::IterStatusExt;
+///
+/// let v: Vec<_> = ['a', 'b', 'c'].iter()
+///     .with_status()
+///     .rev()
+///     .map(|(c, status)| (*c, status.index(), status.is_last()))
+///     .collect();
+///
+/// assert_eq!(v, [('c', 2, true), ('b', 1, false), ('a', 0, false)]);
+/// ```
+impl<I> DoubleEndedIterator for WithStatus<I>
+where
+    I: DoubleEndedIterator + ExactSizeIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // If the underlying iterator is empty, the buffered items are the
+        // last ones.
+        let item = match self.iter.next_back() {
+            Some(item) => item,
+            None => self.peeked_next.take().or_else(|| self.peeked.take())?,
+        };
+
+        // All items that are still in the underlying iterator or our buffer
+        // come before the item we just got.
+        let status = Status {
+            index: self.index + self.len(),
+            last: self.back == 0,
+            remaining: Some(self.back),
+        };
+        self.back += 1;
+
+        Some((item, status))
+    }
+
+    // See the comment about `try_fold` in the `Iterator` impl.
+
+    fn rfind<P>(&mut self, mut predicate: P) -> Option<Self::Item>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        // The index of the item right before the last item we yielded from
+        // the back.
+        let mut index = self.index + self.len();
+        let back = &mut self.back;
+
+        let res = self.iter.try_rfold((), |(), item| {
+            index -= 1;
+            let status = Status {
+                index,
+                last: *back == 0,
+                remaining: Some(*back),
+            };
+            *back += 1;
+
+            let item = (item, status);
+            if predicate(&item) {
+                ControlFlow::Break(item)
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+
+        match res {
+            ControlFlow::Break(item) => Some(item),
+
+            // The underlying iterator is exhausted, but we might still have
+            // buffered items.
+            ControlFlow::Continue(()) => {
+                while let Some(item) = self.next_back() {
+                    if predicate(&item) {
+                        return Some(item);
+                    }
+                }
+                None
+            }
+        }
+    }
+}
+
+/// Returns the number of remaining items of `iter` if its `size_hint` is
+/// exact. This is always the case for `ExactSizeIterator`s.
+fn exact_len<I: Iterator>(iter: &I) -> Option<usize> {
+    match iter.size_hint() {
+        (lower, Some(upper)) if lower == upper => Some(lower),
+        _ => None,
+    }
+}
+
+// Implement traits when the underlying iterator implements them.
+impl<I: FusedIterator> FusedIterator for WithStatus<I> {}
+
+/// The length always matches the number of items that are still yielded, even
+/// though `WithStatus` internally fetches one item in advance.
+///
+/// # Example
+///
+/// ```
+/// use splop::IterStatusExt;
+///
+/// let mut iter = [1, 2, 3].iter().with_status();
+/// assert_eq!(iter.len(), 3);
+///
+/// iter.next();
+/// assert_eq!(iter.len(), 2);
+/// assert_eq!(iter.size_hint(), (2, Some(2)));
+/// ```
+impl<I: ExactSizeIterator> ExactSizeIterator for WithStatus<I> {
+    fn len(&self) -> usize {
+        self.iter.len() + self.buffered_len()
+    }
+}
+
+/// Items that [`WithStatus`] already fetched from the underlying iterator, but
+/// did not yield yet. Returned by [`WithStatus::into_inner`].
+#[derive(Clone, Debug)]
+pub struct Buffered<T> {
+    first: Option<T>,
+    second: Option<T>,
+}
+
+impl<T> Iterator for Buffered<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.first.take() {
+            Some(item) => Some(item),
+            None => self.second.take(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.first.is_some() as usize + self.second.is_some() as usize;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Buffered<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.second.take() {
+            Some(item) => Some(item),
+            None => self.first.take(),
+        }
+    }
+}
+
+impl<T> FusedIterator for Buffered<T> {}
+impl<T> ExactSizeIterator for Buffered<T> {}
+
+/// Adds `with_status` and related methods to all iterators.
+pub trait IterStatusExt: Iterator + Sized {
+    /// Creates an iterator that yields the original items paired with a
+    /// status, which tells you if the item is the first and/or last one.
+    ///
+    /// The new iterator's item has the type `(Self::Item, Status)`. See
+    /// [`Status`] for detailed information. The new iterator always fetches
+    /// one item in advance (to know whether the current item is the last
+    /// one), so if the `next()` call of the underlying iterator has side
+    /// effects, those will be visible earlier than expected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    ///
+    /// let mut s = String::new();
+    /// let names = ["anna", "peter", "bob"];
+    ///
+    /// for (name, status) in names.iter().with_status() {
+    ///     if !status.is_first() {
+    ///         s += ", ";
+    ///     }
+    ///
+    ///     s += name;
+    /// }
+    ///
+    /// assert_eq!(s, "anna, peter, bob");
+    /// ```
+    fn with_status(self) -> WithStatus<Self>;
+
+    /// Like [`with_status`][IterStatusExt::with_status], but only fetches the
+    /// next item when you ask whether the current item is the last one.
+    ///
+    /// The new iterator's item has the type `(Self::Item, LazyStatus<Self>)`.
+    /// As long as you don't call methods like [`LazyStatus::is_last`], the
+    /// underlying iterator is advanced exactly when you advance the new one.
+    /// This is useful if `next()` of the underlying iterator has side effects
+    /// (e.g. when reading from a channel or a database cursor). The price for
+    /// this is a small overhead, as the status shares state with the
+    /// iterator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use splop::IterStatusExt;
+    ///
+    /// let fetched = Cell::new(0);
+    /// let mut iter = (0..3)
+    ///     .inspect(|_| fetched.set(fetched.get() + 1))
+    ///     .with_status_lazy();
+    ///
+    /// let (_, status) = iter.next().unwrap();
+    /// assert!(status.is_first());
+    /// assert_eq!(fetched.get(), 1);
+    ///
+    /// // Only now the next item is fetched.
+    /// assert!(!status.is_last());
+    /// assert_eq!(fetched.get(), 2);
+    ///
+    /// let (_, status) = iter.next().unwrap();
+    /// assert_eq!(status.index(), 1);
+    /// assert_eq!(fetched.get(), 2);
+    /// ```
+    fn with_status_lazy(self) -> WithStatusLazy<Self>;
+
+    /// Like [`with_status`][IterStatusExt::with_status], but determines the
+    /// status by counting instead of looking ahead. Only available for
+    /// `ExactSizeIterator`s.
+    ///
+    /// The new iterator never fetches an item in advance, so side effects of
+    /// the underlying iterator happen exactly when you advance the new one,
+    /// and no item has to be buffered. As this relies on `len()` of the
+    /// underlying iterator, a wrong `ExactSizeIterator` implementation leads
+    /// to wrong statuses.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let v: Vec<_> = ["anna", "peter", "bob"].iter()
+    ///     .with_status_counted()
+    ///     .map(|(name, status)| (*name, status.is_last()))
+    ///     .collect();
+    ///
+    /// assert_eq!(v, [("anna", false), ("peter", false), ("bob", true)]);
+    /// ```
+    fn with_status_counted(self) -> WithStatusCounted<Self>
+    where
+        Self: ExactSizeIterator;
+
+    /// Creates an iterator that applies `f` to the first item and yields all
+    /// other items unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let v: Vec<_> = "hello big world".split(' ')
+    ///     .map(|s| s.to_string())
+    ///     .map_first(|s| s.to_uppercase())
+    ///     .collect();
+    ///
+    /// assert_eq!(v, ["HELLO", "big", "world"]);
+    /// ```
+    fn map_first<F>(self, f: F) -> MapFirst<Self, F>
+    where
+        F: FnOnce(Self::Item) -> Self::Item;
+
+    /// Creates an iterator that applies `f` to the last item and yields all
+    /// other items unchanged.
+    ///
+    /// Like [`with_status`][IterStatusExt::with_status], this fetches one item
+    /// in advance to know whether the current item is the last one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let s: String = ["apple", "banana", "kiwi"].iter()
+    ///     .map(|s| format!("{}, ", s))
+    ///     .map_last(|s| s.replace(", ", "."))
+    ///     .collect();
+    ///
+    /// assert_eq!(s, "apple, banana, kiwi.");
+    /// ```
+    fn map_last<F>(self, f: F) -> MapLast<Self, F>
+    where
+        F: FnOnce(Self::Item) -> Self::Item;
+
+    /// Creates an iterator that applies `f` to all items that are neither the
+    /// first nor the last one and yields the first and last item unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let lines: Vec<_> = ["/*", "foo", "bar", "*/"].iter()
+    ///     .map(|s| s.to_string())
+    ///     .map_in_between(|s| format!(" * {}", s))
+    ///     .collect();
+    ///
+    /// assert_eq!(lines, ["/*", " * foo", " * bar", "*/"]);
+    /// ```
+    fn map_in_between<F>(self, f: F) -> MapInBetween<Self, F>
+    where
+        F: FnMut(Self::Item) -> Self::Item;
+
+    /// Creates an iterator that calls `f` right before its first item is
+    /// yielded. If the iterator is empty, `f` is never called.
+    ///
+    /// Together with [`after_last`][IterStatusExt::after_last] this is useful
+    /// to set up and tear down something around a loop, but only if there is
+    /// anything to loop over.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::cell::RefCell;
+    /// use splop::IterStatusExt;
+    ///
+    /// let log = RefCell::new(Vec::new());
+    /// let items = ["a", "b"].iter()
+    ///     .before_first(|| log.borrow_mut().push("open"))
+    ///     .after_last(|| log.borrow_mut().push("close"));
+    ///
+    /// for item in items {
+    ///     log.borrow_mut().push(item);
+    /// }
+    ///
+    /// assert_eq!(*log.borrow(), ["open", "a", "b", "close"]);
+    /// ```
+    fn before_first<F>(self, f: F) -> BeforeFirst<Self, F>
+    where
+        F: FnOnce();
+
+    /// Creates an iterator that calls `f` right after its last item was
+    /// yielded. If the iterator is empty, `f` is never called.
+    ///
+    /// `f` is called as soon as the underlying iterator returns `None` for the
+    /// first time, i.e. when a `for` loop over this iterator ends after the
+    /// body ran for the last item. It is not called if the iterator is
+    /// dropped before being exhausted (e.g. by `break`ing out of the loop).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use splop::IterStatusExt;
+    ///
+    /// let done = Cell::new(false);
+    /// let mut iter = [1, 2].iter().after_last(|| done.set(true));
+    ///
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert!(!done.get());
+    ///
+    /// assert_eq!(iter.next(), None);
+    /// assert!(done.get());
+    /// ```
+    fn after_last<F>(self, f: F) -> AfterLast<Self, F>
+    where
+        F: FnOnce();
+
+    /// Creates an iterator that calls `f` with a reference to the first item
+    /// before yielding it. All items are yielded unchanged.
+    ///
+    /// This is like [`Iterator::inspect`], but only for the first item.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::cell::RefCell;
+    /// use splop::IterStatusExt;
+    ///
+    /// let log = RefCell::new(Vec::new());
+    /// let sum: u32 = [3, 4, 5].iter()
+    ///     .tap_first(|x| log.borrow_mut().push(format!("started with {}", x)))
+    ///     .tap_last(|x| log.borrow_mut().push(format!("finished with {}", x)))
+    ///     .sum();
+    ///
+    /// assert_eq!(sum, 12);
+    /// assert_eq!(*log.borrow(), ["started with 3", "finished with 5"]);
+    /// ```
+    fn tap_first<F>(self, f: F) -> TapFirst<Self, F>
+    where
+        F: FnOnce(&Self::Item);
+
+    /// Creates an iterator that calls `f` with a reference to the last item
+    /// before yielding it. All items are yielded unchanged.
+    ///
+    /// This is like [`Iterator::inspect`], but only for the last item. Like
+    /// [`with_status`][IterStatusExt::with_status], this fetches one item in
+    /// advance to know whether the current item is the last one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use splop::IterStatusExt;
+    ///
+    /// let last = Cell::new(None);
+    /// let v: Vec<_> = (1..4).tap_last(|&x| last.set(Some(x))).collect();
+    ///
+    /// assert_eq!(v, [1, 2, 3]);
+    /// assert_eq!(last.get(), Some(3));
+    /// ```
+    fn tap_last<F>(self, f: F) -> TapLast<Self, F>
+    where
+        F: FnOnce(&Self::Item);
+
+    /// Creates an iterator that yields all items except the last one.
+    ///
+    /// This doesn't collect the items: it only buffers a single item to know
+    /// whether it's the last one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let csv = "name,age\nPeter,31\nSusan,27\ntotal: 2";
+    /// let rows: Vec<_> = csv.lines().skip(1).skip_last().collect();
+    ///
+    /// assert_eq!(rows, ["Peter,31", "Susan,27"]);
+    /// ```
+    fn skip_last(self) -> SkipLast<Self>;
+
+    /// Creates an iterator that yields all items except the last `n` ones.
+    ///
+    /// This doesn't collect the items: it only buffers `n` items in a ring
+    /// buffer. `skip_last_n(1)` is equivalent to
+    /// [`skip_last`][IterStatusExt::skip_last], and `skip_last_n(0)` yields
+    /// all items.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let report = "Peter,31\nSusan,27\n---\ntotal: 2\naverage: 29";
+    /// let rows: Vec<_> = report.lines().skip_last_n(3).collect();
+    ///
+    /// assert_eq!(rows, ["Peter,31", "Susan,27"]);
+    /// ```
+    fn skip_last_n(self, n: usize) -> SkipLastN<Self>;
+
+    /// Like [`with_status`][IterStatusExt::with_status], but looks `n` items
+    /// ahead instead of only one.
+    ///
+    /// The iterator buffers up to `n` items after the current one. This means
+    /// that once fewer than `n` items are left, their number is known, even
+    /// if the length of the underlying iterator is not. Thus,
+    /// [`Status::remaining`] is `Some` for the last `n` items and
+    /// [`Status::is_within_last`] works for any value up to `n`. A lookahead
+    /// of 0 is treated like 1, which is what `with_status` does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let lines = "a\nb\nc\nd\ne".lines();
+    ///
+    /// let v: Vec<_> = lines
+    ///     .with_lookahead(2)
+    ///     .map(|(line, status)| (line, status.is_within_last(2)))
+    ///     .collect();
+    ///
+    /// assert_eq!(v, [("a", false), ("b", false), ("c", false), ("d", true), ("e", true)]);
+    /// ```
+    fn with_lookahead(self, n: usize) -> WithLookahead<Self>;
+
+    /// Creates an iterator that places a separator between every two items.
+    /// The separator is created on demand by calling `f`.
+    ///
+    /// As the separator is not cloned, the item type doesn't need to be
+    /// `Clone` and the separators can differ from one another.
+    ///
+    /// Note that the standard library has an unstable method with the same
+    /// name. If it's ever stabilized, call this method via
+    /// `IterStatusExt::intersperse_with(iter, f)` to avoid the ambiguity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let mut count = 0;
+    /// let s: String = ["a", "b", "c"].iter()
+    ///     .map(|s| s.to_string())
+    ///     .intersperse_with(|| {
+    ///         count += 1;
+    ///         format!(" {} ", count)
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(s, "a 1 b 2 c");
+    /// ```
+    fn intersperse_with<F>(self, f: F) -> IntersperseWith<Self, F>
+    where
+        F: FnMut() -> Self::Item;
+
+    /// Creates an iterator that places `sep` between every two items and
+    /// `term` after the last item. An empty iterator stays empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let s: String = ["let a = 3", "let b = a", "b"].iter()
+    ///     .cloned()
+    ///     .terminate_with(";\n", "\n")
+    ///     .collect();
+    ///
+    /// assert_eq!(s, "let a = 3;\nlet b = a;\nb\n");
+    /// ```
+    fn terminate_with(self, sep: Self::Item, term: Self::Item) -> TerminateWith<Self>
+    where
+        Self::Item: Clone;
+
+    /// Creates an iterator that yields [`Token`]s: first `open`, then all
+    /// items with `sep` between every two of them and finally `close`.
+    ///
+    /// The delimiters are yielded even if the iterator is empty. This is
+    /// useful for pretty printers, as rendering a list becomes a simple
+    /// `match` over the tokens.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::{IterStatusExt, Token};
+    ///
+    /// fn render(list: &[Vec<u32>]) -> String {
+    ///     let mut out = String::new();
+    ///     for token in list.iter().delimit("[", ", ", "]") {
+    ///         match token {
+    ///             Token::Open(s) | Token::Sep(s) | Token::Close(s) => out.push_str(s),
+    ///             Token::Item(inner) => {
+    ///                 for token in inner.iter().delimit('(', ' ', ')') {
+    ///                     match token {
+    ///                         Token::Item(x) => out.push_str(&x.to_string()),
+    ///                         Token::Open(c) | Token::Sep(c) | Token::Close(c) => {
+    ///                             out.push(c)
+    ///                         }
+    ///                     }
+    ///                 }
+    ///             }
+    ///         }
+    ///     }
+    ///     out
+    /// }
+    ///
+    /// assert_eq!(render(&[vec![1, 2], vec![], vec![3]]), "[(1 2), (), (3)]");
+    /// assert_eq!(render(&[]), "[]");
+    /// ```
+    fn delimit<D: Clone>(self, open: D, sep: D, close: D) -> Delimit<Self, D>;
+
+    /// Creates an iterator that yields [`Element::Header`] before the first
+    /// item, all items with their status as [`Element::Item`] and
+    /// [`Element::Footer`] after the last item.
+    ///
+    /// If the iterator is empty, neither header nor footer are yielded. Use
+    /// [`Surround::even_if_empty`] to change that.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::{Element, IterStatusExt};
+    ///
+    /// let mut html = String::new();
+    /// for element in ["Peter", "Susan"].iter().surround() {
+    ///     match element {
+    ///         Element::Header => html.push_str("<table>\n"),
+    ///         Element::Item(name, status) => {
+    ///             html.push_str(&format!("  <tr><td>{}</td><td>{}</td></tr>\n",
+    ///                 status.index() + 1, name));
+    ///         }
+    ///         Element::Footer => html.push_str("</table>\n"),
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(html, "<table>\n  \
+    ///     <tr><td>1</td><td>Peter</td></tr>\n  \
+    ///     <tr><td>2</td><td>Susan</td></tr>\n\
+    ///     </table>\n");
+    /// ```
+    fn surround(self) -> Surround<Self>;
+
+    /// Creates an iterator that yields every item together with the previous
+    /// and the next item: `(prev, current, next)`. For the first item, `prev`
+    /// is `None`; for the last item, `next` is `None`.
+    ///
+    /// The neighbors are cloned, so this requires `Self::Item: Clone`. For
+    /// iterators over references that's cheap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// // Only put a space between two words, not between a word and a
+    /// // punctuation mark.
+    /// let mut s = String::new();
+    /// for (_, word, next) in ["Hello", ",", "world", "!"].iter().with_neighbors() {
+    ///     s.push_str(word);
+    ///     if let Some(next) = next {
+    ///         if next.chars().all(char::is_alphabetic) {
+    ///             s.push(' ');
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(s, "Hello, world!");
+    /// ```
+    fn with_neighbors(self) -> WithNeighbors<Self>
+    where
+        Self::Item: Clone;
+
+    /// Creates an iterator that yields all overlapping windows of `N`
+    /// consecutive items together with the status of the window.
+    ///
+    /// The status refers to the windows, not the items: the first window is
+    /// first and the window containing the last item is last. If the
+    /// iterator has fewer than `N` items, no window is yielded. The items are
+    /// cloned into the windows, so this requires `Self::Item: Clone`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let v: Vec<_> = [1, 4, 9, 16].iter()
+    ///     .windows_with_status::<2>()
+    ///     .map(|([a, b], status)| (b - a, status.is_last()))
+    ///     .collect();
+    ///
+    /// assert_eq!(v, [(3, false), (5, false), (7, true)]);
+    /// ```
+    fn windows_with_status<const N: usize>(self) -> WindowsWithStatus<Self, N>
+    where
+        Self::Item: Clone;
+
+    /// Creates an iterator that yields every item together with its
+    /// [`GroupStatus`], where a group is a run of consecutive items for which
+    /// `key` returns the same value.
+    ///
+    /// Besides the status of the item within the whole iterator, the
+    /// `GroupStatus` tells you whether the item is the first or last of its
+    /// group. Like [`with_status`][IterStatusExt::with_status], this only
+    /// fetches one item in advance; the key function is called twice per
+    /// item. This is most useful for data that is sorted by the key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let people = [("Peter", 'A'), ("Susan", 'A'), ("Tom", 'B'), ("Ursula", 'C')];
+    ///
+    /// let mut s = String::new();
+    /// for (&(name, team), status) in people.iter().with_group_status(|p| p.1) {
+    ///     if status.is_group_first() {
+    ///         s.push_str(&format!("{}: ", team));
+    ///     }
+    ///     s.push_str(name);
+    ///     if !status.is_group_last() {
+    ///         s.push_str(", ");
+    ///     } else if !status.global().is_last() {
+    ///         s.push_str("; ");
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(s, "A: Peter, Susan; B: Tom; C: Ursula");
+    /// ```
+    fn with_group_status<F, K>(self, key: F) -> WithGroupStatus<Self, F>
+    where
+        F: FnMut(&Self::Item) -> K,
+        K: PartialEq;
+
+    /// Creates an iterator that yields every item together with its
+    /// [`GroupStatus`], where a group is a run of consecutive equal items.
+    ///
+    /// This is useful for run-length style output without allocating the
+    /// runs. To compare items with a custom predicate, use
+    /// [`with_run_status_by`][IterStatusExt::with_run_status_by].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let mut s = String::new();
+    /// for (c, status) in "aaabccdd".chars().with_run_status() {
+    ///     if status.is_group_last() {
+    ///         s.push_str(&format!("{}{}", status.local().index() + 1, c));
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(s, "3a1b2c2d");
+    /// ```
+    fn with_run_status(self) -> WithRunStatus<Self, PartialEqFn<Self::Item>>
+    where
+        Self::Item: PartialEq;
+
+    /// Like [`with_run_status`][IterStatusExt::with_run_status], but two
+    /// consecutive items belong to the same run if `eq` returns `true` for
+    /// them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let v: Vec<_> = ["Apple", "avocado", "Banana", "blueberry", "cherry"].iter()
+    ///     .with_run_status_by(|a, b| {
+    ///         a.chars().next().map(|c| c.to_ascii_lowercase())
+    ///             == b.chars().next().map(|c| c.to_ascii_lowercase())
+    ///     })
+    ///     .map(|(_, status)| status.group_index())
+    ///     .collect();
+    ///
+    /// assert_eq!(v, [0, 0, 1, 1, 2]);
+    /// ```
+    fn with_run_status_by<F>(self, eq: F) -> WithRunStatus<Self, F>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> bool;
+
+    /// Creates an iterator that yields every item together with its
+    /// [`ChunkStatus`], which tells you how the items are split into chunks
+    /// of `chunk_size` items.
+    ///
+    /// The last chunk might be smaller than `chunk_size`; its last item is
+    /// still reported as [`is_last_in_chunk`][ChunkStatus::is_last_in_chunk].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let mut sql = String::new();
+    /// for (id, status) in (1..=5).with_chunk_status(2) {
+    ///     if status.is_first_in_chunk() {
+    ///         sql.push_str("INSERT INTO t VALUES ");
+    ///     } else {
+    ///         sql.push_str(", ");
+    ///     }
+    ///     sql.push_str(&format!("({})", id));
+    ///     if status.is_last_in_chunk() {
+    ///         sql.push_str(";\n");
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(sql, "\
+    ///     INSERT INTO t VALUES (1), (2);\n\
+    ///     INSERT INTO t VALUES (3), (4);\n\
+    ///     INSERT INTO t VALUES (5);\n\
+    /// ");
+    /// ```
+    fn with_chunk_status(self, chunk_size: usize) -> WithChunkStatus<Self>;
+
+    /// Creates an iterator that yields every item together with its
+    /// [`GroupStatus`], where `is_boundary` decides where one segment (group)
+    /// ends and the next one starts.
+    ///
+    /// `is_boundary` is called with every two adjacent items `(prev, next)`
+    /// and returns `true` if `next` starts a new segment. Whether an item
+    /// starts or ends a segment is then available via
+    /// [`is_group_first`][GroupStatus::is_group_first] and
+    /// [`is_group_last`][GroupStatus::is_group_last]; the global first/last
+    /// status is still available via [`global`][GroupStatus::global]. This
+    /// generalizes [`with_group_status`][IterStatusExt::with_group_status]
+    /// and [`with_run_status`][IterStatusExt::with_run_status].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// // Split timestamps into sessions with gaps of more than 10 minutes.
+    /// let minutes = [0, 3, 5, 30, 31, 55];
+    /// let v: Vec<_> = minutes.iter()
+    ///     .with_boundaries(|prev, next| *next - *prev > 10)
+    ///     .filter(|(_, status)| status.is_group_first())
+    ///     .map(|(start, _)| *start)
+    ///     .collect();
+    ///
+    /// assert_eq!(v, [0, 30, 55]);
+    /// ```
+    fn with_boundaries<F>(self, is_boundary: F) -> WithBoundaries<Self, F>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> bool;
+
+    /// Creates an iterator that splits the items into segments separated by
+    /// items for which `is_delimiter` returns `true`. The delimiters are
+    /// dropped; all other items are yielded together with their
+    /// [`GroupStatus`] within their segment.
+    ///
+    /// The [`group_index`][GroupStatus::group_index] is the index of the
+    /// segment, i.e. the number of delimiters before the item. Thus, empty
+    /// segments (e.g. between two adjacent delimiters) are counted, even
+    /// though no item is yielded for them. Since delimiters are dropped,
+    /// [`global`][GroupStatus::global] refers to the position of the item in
+    /// the underlying iterator, including delimiters. `is_delimiter` is
+    /// called twice for most items.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let text = "Lorem ipsum\ndolor sit.\n\nAmet.";
+    ///
+    /// let mut html = String::new();
+    /// for (line, status) in text.lines().split_when(|line| line.is_empty()) {
+    ///     if status.is_group_first() {
+    ///         html.push_str("<p>");
+    ///     }
+    ///     html.push_str(line);
+    ///     if status.is_group_last() {
+    ///         html.push_str("</p>");
+    ///     } else {
+    ///         html.push(' ');
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(html, "<p>Lorem ipsum dolor sit.</p><p>Amet.</p>");
+    /// ```
+    fn split_when<P>(self, is_delimiter: P) -> SplitWhen<Self, P>
+    where
+        P: FnMut(&Self::Item) -> bool;
+
+    /// Zips this iterator with `other` and yields every pair together with
+    /// its [`ZipStatus`].
+    ///
+    /// Besides the [`Status`] of the pair, the status of the last pair also
+    /// tells you which of the iterators ran out of items, so you can detect
+    /// inputs of different lengths. If one of the iterators is empty, no pair
+    /// and thus no status is yielded.
+    ///
+    /// Like [`with_status`][IterStatusExt::with_status], this reads one item
+    /// ahead from both iterators. When `other` runs out before `self`, the
+    /// item read ahead from `self` is dropped and never yielded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::{Exhausted, IterStatusExt};
+    ///
+    /// let names = ["Peter", "Susan", "Tom"];
+    /// let ages = [31, 27];
+    ///
+    /// let mut lines = Vec::new();
+    /// for ((name, age), status) in names.iter().zip_with_status(&ages) {
+    ///     lines.push(format!("{} is {}", name, age));
+    ///     if status.exhausted() == Some(Exhausted::Right) {
+    ///         lines.push("more names than ages".to_string());
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(lines, ["Peter is 31", "Susan is 27", "more names than ages"]);
+    /// ```
+    fn zip_with_status<U>(self, other: U) -> ZipWithStatus<Self, U::IntoIter>
+    where
+        U: IntoIterator;
+
+    /// Chains this iterator with `other` and yields every item together with
+    /// its [`GroupStatus`], where the group is the iterator the item comes
+    /// from.
+    ///
+    /// The [`group_index`][GroupStatus::group_index] is 0 for items of `self`
+    /// and 1 for items of `other`. [`local`][GroupStatus::local] is the
+    /// status within the source iterator, [`global`][GroupStatus::global] the
+    /// status within the chained iterator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let header = ["name", "age"];
+    /// let body = ["Peter", "31"];
+    ///
+    /// let mut s = String::new();
+    /// for (cell, status) in header.iter().chain_with_status(&body) {
+    ///     s.push_str(cell);
+    ///     if status.is_group_last() && !status.global().is_last() {
+    ///         s.push_str("\n----\n");
+    ///     } else if !status.global().is_last() {
+    ///         s.push_str(" | ");
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(s, "name | age\n----\nPeter | 31");
+    /// ```
+    fn chain_with_status<U>(self, other: U) -> ChainWithStatus<Self, U::IntoIter>
+    where
+        U: IntoIterator<Item = Self::Item>;
+
+    /// Calls `f` with every item and its [`Status`].
+    ///
+    /// This is equivalent to a `for` loop over
+    /// [`with_status`][IterStatusExt::with_status], but doesn't require
+    /// destructuring tuples and uses internal iteration, which can be faster
+    /// for some iterators (e.g. `Chain`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let mut s = String::new();
+    /// ["a", "b", "c"].iter().for_each_with_status(|item, status| {
+    ///     s.push_str(item);
+    ///     if !status.is_last() {
+    ///         s.push_str(", ");
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(s, "a, b, c");
+    /// ```
+    fn for_each_with_status<F>(self, f: F)
+    where
+        F: FnMut(Self::Item, Status);
+
+    /// Calls the fallible function `f` with every item and its [`Status`],
+    /// stopping at the first error and returning it.
+    ///
+    /// This is like [`for_each_with_status`][IterStatusExt::for_each_with_status]
+    /// for closures returning a `Result`, e.g. `fmt::Result` when
+    /// implementing `Display`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::fmt;
+    /// use splop::IterStatusExt;
+    ///
+    /// struct List<'a>(&'a [u32]);
+    ///
+    /// impl fmt::Display for List<'_> {
+    ///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    ///         self.0.iter().try_for_each_with_status(|x, status| {
+    ///             write!(f, "{}", x)?;
+    ///             if !status.is_last() {
+    ///                 write!(f, " -> ")?;
+    ///             }
+    ///             Ok(())
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(List(&[1, 2, 3]).to_string(), "1 -> 2 -> 3");
+    /// ```
+    fn try_for_each_with_status<F, E>(self, f: F) -> Result<(), E>
+    where
+        F: FnMut(Self::Item, Status) -> Result<(), E>;
+
+    /// Folds every item and its [`Status`] into an accumulator by applying
+    /// `f(acc, item, status)`, starting with `init`, and returns the final
+    /// accumulator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let s = ["Rust", "is", "fun"].iter()
+    ///     .fold_with_status(String::new(), |mut acc, word, status| {
+    ///         acc.push_str(word);
+    ///         acc.push_str(if status.is_last() { "!" } else { " " });
+    ///         acc
+    ///     });
+    ///
+    /// assert_eq!(s, "Rust is fun!");
+    /// ```
+    fn fold_with_status<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, Self::Item, Status) -> B;
+
+    /// Reduces the items to a single one by repeatedly applying
+    /// `f(acc, item, status)`, where `status` is the [`Status`] of the
+    /// incoming `item`. Returns `None` if the iterator is empty.
+    ///
+    /// The first item is the initial accumulator, so `f` is first called with
+    /// the second item (whose status has index 1).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let list = |names: &[&str]| {
+    ///     names.iter()
+    ///         .map(|s| s.to_string())
+    ///         .reduce_with_status(|acc, name, status| {
+    ///             let sep = if status.is_last() { " and " } else { ", " };
+    ///             acc + sep + &name
+    ///         })
+    /// };
+    ///
+    /// assert_eq!(list(&["Peter", "Susan", "Tom"]).unwrap(), "Peter, Susan and Tom");
+    /// assert_eq!(list(&["Peter"]).unwrap(), "Peter");
+    /// assert_eq!(list(&[]), None);
+    /// ```
+    fn reduce_with_status<F>(self, f: F) -> Option<Self::Item>
+    where
+        F: FnMut(Self::Item, Self::Item, Status) -> Self::Item;
+
+    /// Creates an iterator like `Iterator::scan`, but the closure also
+    /// receives the [`Status`] of every item: `f(&mut state, item, status)`.
+    ///
+    /// Like with `scan`, the iteration stops as soon as `f` returns `None`.
+    /// Knowing the status is useful to flush the state on the last item.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// // Group words into lines of at most 10 characters.
+    /// let lines: Vec<_> = "the quick brown fox jumps over the lazy dog"
+    ///     .split(' ')
+    ///     .scan_with_status(String::new(), |line, word, status| {
+    ///         let mut out = Vec::new();
+    ///         if !line.is_empty() && line.len() + 1 + word.len() > 10 {
+    ///             out.push(std::mem::take(line));
+    ///         }
+    ///         if !line.is_empty() {
+    ///             line.push(' ');
+    ///         }
+    ///         line.push_str(word);
+    ///         if status.is_last() {
+    ///             out.push(std::mem::take(line));
+    ///         }
+    ///         Some(out)
+    ///     })
+    ///     .flatten()
+    ///     .collect();
+    ///
+    /// assert_eq!(lines, ["the quick", "brown fox", "jumps over", "the lazy", "dog"]);
+    /// ```
+    fn scan_with_status<St, F, B>(self, init: St, f: F) -> ScanWithStatus<Self, St, F>
+    where
+        F: FnMut(&mut St, Self::Item, Status) -> Option<B>;
+
+    /// Creates an iterator that maps every item together with its [`Status`]
+    /// to a new value by calling `f(item, status)`.
+    ///
+    /// This is equivalent to `.with_status().map(|(item, status)| ...)`, but
+    /// reads nicer and avoids the nested tuple.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let v: Vec<_> = ["a", "b", "c"].iter()
+    ///     .map_with_status(|s, status| format!("{}{}", status.index(), s))
+    ///     .collect();
+    ///
+    /// assert_eq!(v, ["0a", "1b", "2c"]);
+    /// ```
+    fn map_with_status<F, B>(self, f: F) -> MapWithStatus<Self, F>
+    where
+        F: FnMut(Self::Item, Status) -> B;
+
+    /// Creates an iterator that only yields the items for which
+    /// `predicate(&item, status)` returns `true`.
+    ///
+    /// The status passed to `predicate` always refers to the position of the
+    /// item in the original (unfiltered) iterator. The yielded items don't
+    /// carry a status; if you need the status within the filtered items,
+    /// call [`with_status`][IterStatusExt::with_status] on the result, which
+    /// computes a new status.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let csv = "name,age\nPeter,31\nSusan,27";
+    /// let rows: Vec<_> = csv.lines()
+    ///     .filter_with_status(|_, status| !status.is_first())
+    ///     .collect();
+    ///
+    /// assert_eq!(rows, ["Peter,31", "Susan,27"]);
+    /// ```
+    fn filter_with_status<P>(self, predicate: P) -> FilterWithStatus<Self, P>
+    where
+        P: FnMut(&Self::Item, Status) -> bool;
+
+    /// Creates an iterator that calls `f` with a reference to every item and
+    /// its [`Status`] before yielding the item unchanged.
+    ///
+    /// This is like `Iterator::inspect`, but with the status, which is useful
+    /// to trace first/last events somewhere in a long adapter chain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let mut log = Vec::new();
+    /// let sum: u32 = [1, 2, 3].iter()
+    ///     .inspect_with_status(|x, status| {
+    ///         if status.is_first() {
+    ///             log.push(format!("first: {}", x));
+    ///         }
+    ///         if status.is_last() {
+    ///             log.push(format!("last: {}", x));
+    ///         }
+    ///     })
+    ///     .sum();
+    ///
+    /// assert_eq!(sum, 6);
+    /// assert_eq!(log, ["first: 1", "last: 3"]);
+    /// ```
+    fn inspect_with_status<F>(self, f: F) -> InspectWithStatus<Self, F>
+    where
+        F: FnMut(&Self::Item, Status);
+
+    /// Creates a value that, when formatted with `Display`, formats every
+    /// item with `format` and places `sep` (any [`ListFormat`]) between every
+    /// two items.
+    ///
+    /// `format` is called with each item and a callback to which it passes
+    /// anything that implements `Display` (e.g. `&format_args!(...)`). This
+    /// is useful for items that need escaping or custom rendering, while
+    /// the separators are still handled for you.
+    ///
+    /// Note that itertools has a method with the same name, so if you import
+    /// both traits, call this as `IterStatusExt::format_with(iter, sep, f)`.
+    ///
+    /// # Panics
+    ///
+    /// The returned value consumes the iterator when formatted, so it panics
+    /// if it's formatted more than once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let pairs = [("a", 1), ("b", 2)];
+    /// let s = pairs.iter()
+    ///     .format_with(", ", |(k, v), f| f(&format_args!("{}={}", k, v)))
+    ///     .to_string();
+    ///
+    /// assert_eq!(s, "a=1, b=2");
+    /// ```
+    fn format_with<S, F>(self, sep: S, format: F) -> FormatWith<Self, S, F>
+    where
+        S: ListFormat,
+        F: FnMut(Self::Item, &mut dyn FnMut(&dyn fmt::Display) -> fmt::Result) -> fmt::Result;
+
+    /// Collects the `Display` representations of all items into a
+    /// [`Separated`], with `sep` between every two of them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::{IterStatusExt, Separated};
+    ///
+    /// let fields = ["id", "name"];
+    /// let s: Separated<String> = fields.iter()
+    ///     .map(|f| format!("`{}`", f))
+    ///     .collect_with_sep(", ");
+    ///
+    /// assert_eq!(s.into_inner(), "`id`, `name`");
+    /// ```
+    fn collect_with_sep<S>(self, sep: &str) -> Separated<S>
+    where
+        S: Default + fmt::Write,
+        Self::Item: fmt::Display;
+
+    /// Creates an iterator that pairs every item with a prefix from `markers`
+    /// depending on its position, e.g. to draw a vertical guide next to a
+    /// list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::{IterStatusExt, TreeMarkers};
+    ///
+    /// let lines: Vec<_> = ['a', 'b', 'c'].iter()
+    ///     .tree_prefixes(TreeMarkers::BOX)
+    ///     .map(|(prefix, c)| format!("{} {}", prefix, c))
+    ///     .collect();
+    /// assert_eq!(lines, ["┏ a", "┃ b", "┗ c"]);
+    ///
+    /// let lines: Vec<_> = ['a'].iter()
+    ///     .tree_prefixes(TreeMarkers::ASCII)
+    ///     .map(|(prefix, c)| format!("{} {}", prefix, c))
+    ///     .collect();
+    /// assert_eq!(lines, ["- a"]);
+    /// ```
+    fn tree_prefixes<'a>(self, markers: TreeMarkers<'a>) -> TreePrefixes<'a, Self>;
+
+    /// Like [`with_status`][IterStatusExt::with_status], but emits `tracing`
+    /// events at the `DEBUG` level. Requires the `tracing` feature.
+    ///
+    /// The events are emitted inside a `DEBUG` span called
+    /// `with_status_traced`, whose field `name` is set to `name`:
+    ///
+    /// - "yielding first item" when the first item is yielded.
+    /// - "completed last item" with the field `count` (the number of items)
+    ///   when the next item is requested after the last one, i.e. when a
+    ///   `for` loop over the iterator completes. It's not emitted for empty
+    ///   iterators and not if the iterator is dropped before that, e.g. when
+    ///   the loop is left with `break`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "tracing")]
+    /// # fn main() {
+    /// use splop::IterStatusExt;
+    ///
+    /// let mut sum = 0;
+    /// for (i, status) in (1..=3).with_status_traced("summing") {
+    ///     sum += i;
+    ///     if status.is_last() {
+    ///         assert_eq!(sum, 6);
+    ///     }
+    /// }
+    /// # }
+    /// # #[cfg(not(feature = "tracing"))]
+    /// # fn main() {}
+    /// ```
+    #[cfg(feature = "tracing")]
+    fn with_status_traced(self, name: &'static str) -> WithStatusTraced<Self>;
+}
+
+impl<I: Iterator> IterStatusExt for I {
+    fn with_status(self) -> WithStatus<Self> {
         WithStatus::new(self)
     }
+
+    fn with_status_lazy(self) -> WithStatusLazy<Self> {
+        WithStatusLazy::new(self)
+    }
+
+    fn with_status_counted(self) -> WithStatusCounted<Self>
+    where
+        Self: ExactSizeIterator,
+    {
+        WithStatusCounted::new(self)
+    }
+
+    fn map_first<F>(self, f: F) -> MapFirst<Self, F>
+    where
+        F: FnOnce(Self::Item) -> Self::Item,
+    {
+        MapFirst::new(self, f)
+    }
+
+    fn map_last<F>(self, f: F) -> MapLast<Self, F>
+    where
+        F: FnOnce(Self::Item) -> Self::Item,
+    {
+        MapLast::new(self, f)
+    }
+
+    fn map_in_between<F>(self, f: F) -> MapInBetween<Self, F>
+    where
+        F: FnMut(Self::Item) -> Self::Item,
+    {
+        MapInBetween::new(self, f)
+    }
+
+    fn before_first<F>(self, f: F) -> BeforeFirst<Self, F>
+    where
+        F: FnOnce(),
+    {
+        BeforeFirst::new(self, f)
+    }
+
+    fn after_last<F>(self, f: F) -> AfterLast<Self, F>
+    where
+        F: FnOnce(),
+    {
+        AfterLast::new(self, f)
+    }
+
+    fn tap_first<F>(self, f: F) -> TapFirst<Self, F>
+    where
+        F: FnOnce(&Self::Item),
+    {
+        TapFirst::new(self, f)
+    }
+
+    fn tap_last<F>(self, f: F) -> TapLast<Self, F>
+    where
+        F: FnOnce(&Self::Item),
+    {
+        TapLast::new(self, f)
+    }
+
+    fn skip_last(self) -> SkipLast<Self> {
+        SkipLast::new(self)
+    }
+
+    fn skip_last_n(self, n: usize) -> SkipLastN<Self> {
+        SkipLastN::new(self, n)
+    }
+
+    fn with_lookahead(self, n: usize) -> WithLookahead<Self> {
+        WithLookahead::new(self, n)
+    }
+
+    fn intersperse_with<F>(self, f: F) -> IntersperseWith<Self, F>
+    where
+        F: FnMut() -> Self::Item,
+    {
+        IntersperseWith::new(self, f)
+    }
+
+    fn terminate_with(self, sep: Self::Item, term: Self::Item) -> TerminateWith<Self>
+    where
+        Self::Item: Clone,
+    {
+        TerminateWith::new(self, sep, term)
+    }
+
+    fn delimit<D: Clone>(self, open: D, sep: D, close: D) -> Delimit<Self, D> {
+        Delimit::new(self, open, sep, close)
+    }
+
+    fn surround(self) -> Surround<Self> {
+        Surround::new(self)
+    }
+
+    fn with_neighbors(self) -> WithNeighbors<Self>
+    where
+        Self::Item: Clone,
+    {
+        WithNeighbors::new(self)
+    }
+
+    fn windows_with_status<const N: usize>(self) -> WindowsWithStatus<Self, N>
+    where
+        Self::Item: Clone,
+    {
+        WindowsWithStatus::new(self)
+    }
+
+    fn with_group_status<F, K>(self, key: F) -> WithGroupStatus<Self, F>
+    where
+        F: FnMut(&Self::Item) -> K,
+        K: PartialEq,
+    {
+        WithGroupStatus::new(self, key)
+    }
+
+    fn with_run_status(self) -> WithRunStatus<Self, PartialEqFn<Self::Item>>
+    where
+        Self::Item: PartialEq,
+    {
+        WithRunStatus::new(self, PartialEq::eq)
+    }
+
+    fn with_run_status_by<F>(self, eq: F) -> WithRunStatus<Self, F>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        WithRunStatus::new(self, eq)
+    }
+
+    fn with_chunk_status(self, chunk_size: usize) -> WithChunkStatus<Self> {
+        WithChunkStatus::new(self, chunk_size)
+    }
+
+    fn with_boundaries<F>(self, is_boundary: F) -> WithBoundaries<Self, F>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        WithBoundaries::new(self, is_boundary)
+    }
+
+    fn split_when<P>(self, is_delimiter: P) -> SplitWhen<Self, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        SplitWhen::new(self, is_delimiter)
+    }
+
+    fn zip_with_status<U>(self, other: U) -> ZipWithStatus<Self, U::IntoIter>
+    where
+        U: IntoIterator,
+    {
+        ZipWithStatus::new(self, other.into_iter())
+    }
+
+    fn chain_with_status<U>(self, other: U) -> ChainWithStatus<Self, U::IntoIter>
+    where
+        U: IntoIterator<Item = Self::Item>,
+    {
+        ChainWithStatus::new(self, other.into_iter())
+    }
+
+    fn for_each_with_status<F>(self, mut f: F)
+    where
+        F: FnMut(Self::Item, Status),
+    {
+        self.with_status().for_each(|(item, status)| f(item, status));
+    }
+
+    fn try_for_each_with_status<F, E>(self, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(Self::Item, Status) -> Result<(), E>,
+    {
+        self.with_status().try_for_each(|(item, status)| f(item, status))
+    }
+
+    fn fold_with_status<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item, Status) -> B,
+    {
+        self.with_status().fold(init, |acc, (item, status)| f(acc, item, status))
+    }
+
+    fn reduce_with_status<F>(self, mut f: F) -> Option<Self::Item>
+    where
+        F: FnMut(Self::Item, Self::Item, Status) -> Self::Item,
+    {
+        let mut iter = self.with_status();
+        let (first, _) = iter.next()?;
+        Some(iter.fold(first, |acc, (item, status)| f(acc, item, status)))
+    }
+
+    fn scan_with_status<St, F, B>(self, init: St, f: F) -> ScanWithStatus<Self, St, F>
+    where
+        F: FnMut(&mut St, Self::Item, Status) -> Option<B>,
+    {
+        ScanWithStatus::new(self, init, f)
+    }
+
+    fn map_with_status<F, B>(self, f: F) -> MapWithStatus<Self, F>
+    where
+        F: FnMut(Self::Item, Status) -> B,
+    {
+        MapWithStatus::new(self, f)
+    }
+
+    fn filter_with_status<P>(self, predicate: P) -> FilterWithStatus<Self, P>
+    where
+        P: FnMut(&Self::Item, Status) -> bool,
+    {
+        FilterWithStatus::new(self, predicate)
+    }
+
+    fn inspect_with_status<F>(self, f: F) -> InspectWithStatus<Self, F>
+    where
+        F: FnMut(&Self::Item, Status),
+    {
+        InspectWithStatus::new(self, f)
+    }
+
+    fn format_with<S, F>(self, sep: S, format: F) -> FormatWith<Self, S, F>
+    where
+        S: ListFormat,
+        F: FnMut(Self::Item, &mut dyn FnMut(&dyn fmt::Display) -> fmt::Result) -> fmt::Result,
+    {
+        FormatWith::new(self, sep, format)
+    }
+
+    fn collect_with_sep<S>(self, sep: &str) -> Separated<S>
+    where
+        S: Default + fmt::Write,
+        Self::Item: fmt::Display,
+    {
+        let mut out = Separated::with_sep(sep);
+        out.extend(self);
+        out
+    }
+
+    fn tree_prefixes<'a>(self, markers: TreeMarkers<'a>) -> TreePrefixes<'a, Self> {
+        TreePrefixes::new(self, markers)
+    }
+
+    #[cfg(feature = "tracing")]
+    fn with_status_traced(self, name: &'static str) -> WithStatusTraced<Self> {
+        WithStatusTraced::new(self, name)
+    }
 }
 
 /// The status of an item from an iterator (e.g. "is this the first item?").
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///
+/// Two statuses are equal if they have the same [`index`][Status::index] and
+/// both are or both aren't the last item. The number of
+/// [`remaining`][Status::remaining] items is not compared, as it depends on
+/// whether the iterator's length is known. So `Status::new(0, false)` and
+/// `Status::with_len(0, 3)` are equal.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "SerdeStatus", try_from = "SerdeStatus"))]
 pub struct Status {
-    first: bool,
+    index: usize,
     last: bool,
+    remaining: Option<usize>,
 }
 
 impl Status {
+    /// The bit set in [`to_bits`][Status::to_bits] for the first item.
+    pub const FIRST_BIT: u8 = 0b01;
+
+    /// The bit set in [`to_bits`][Status::to_bits] for the last item.
+    pub const LAST_BIT: u8 = 0b10;
+
+    /// Creates the status of the item with the zero-based `index`. `last`
+    /// says whether it is the last item.
+    ///
+    /// The number of [`remaining`][Status::remaining] items is `Some(0)` for
+    /// the last item and unknown otherwise. Use [`Status::with_len`] if the
+    /// length is known.
+    ///
+    /// This is useful for custom iterator adapters and for tests.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::{IterStatusExt, Status};
+    ///
+    /// let v: Vec<_> = (1..8)
+    ///     .filter(|i| i % 3 == 0)
+    ///     .with_status()
+    ///     .map(|(_, status)| status)
+    ///     .collect();
+    ///
+    /// assert_eq!(v, [Status::new(0, false), Status::new(1, true)]);
+    /// ```
+    pub const fn new(index: usize, last: bool) -> Self {
+        Self {
+            index,
+            last,
+            remaining: if last { Some(0) } else { None },
+        }
+    }
+
+    /// Creates the status of the item with the zero-based `index` in a
+    /// sequence of `len` items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not smaller than `len`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::{IterStatusExt, Status};
+    ///
+    /// let v: Vec<_> = ['a', 'b'].iter()
+    ///     .with_status()
+    ///     .map(|(_, status)| status)
+    ///     .collect();
+    ///
+    /// assert_eq!(v, [Status::with_len(0, 2), Status::with_len(1, 2)]);
+    /// assert_eq!(Status::with_len(0, 2), Status::new(0, false));
+    /// assert!(Status::with_len(1, 2).is_last());
+    /// assert_eq!(Status::with_len(0, 2).remaining(), Some(1));
+    /// ```
+    pub const fn with_len(index: usize, len: usize) -> Self {
+        assert!(index < len, "index out of bounds in `Status::with_len`");
+
+        let remaining = len - index - 1;
+        Self {
+            index,
+            last: remaining == 0,
+            remaining: Some(remaining),
+        }
+    }
+
     /// Returns `true` if this is the first item of the iterator.
     ///
     /// Note that an item might simultaniously be the first and last item (if
@@ -226,8 +2509,8 @@ impl Status {
     /// assert!(status.is_first());
     /// assert!(status.is_last());
     /// ```
-    pub fn is_first(&self) -> bool {
-        self.first
+    pub const fn is_first(&self) -> bool {
+        self.index == 0
     }
 
     /// Returns `true` if this is the first item and it's not the only item in
@@ -263,8 +2546,8 @@ impl Status {
     ///
     /// assert!(!status.is_first_only());
     /// ```
-    pub fn is_first_only(&self) -> bool {
-        self.first && !self.last
+    pub const fn is_first_only(&self) -> bool {
+        self.is_first() && !self.last
     }
 
     /// Returns `true` if this is the last item of the iterator.
@@ -305,7 +2588,7 @@ impl Status {
     /// assert!(status.is_first());
     /// assert!(status.is_last());
     /// ```
-    pub fn is_last(&self) -> bool {
+    pub const fn is_last(&self) -> bool {
         self.last
     }
 
@@ -342,8 +2625,8 @@ impl Status {
     ///
     /// assert!(!status.is_last_only());
     /// ```
-    pub fn is_last_only(&self) -> bool {
-        self.last && !self.first
+    pub const fn is_last_only(&self) -> bool {
+        self.last && !self.is_first()
     }
 
     /// Returns `true` if this is neither the first nor the last item.
@@ -365,7 +2648,531 @@ impl Status {
     ///     (3, false),
     /// ]);
     /// ```
-    pub fn is_in_between(&self) -> bool {
-        !self.first && !self.last
+    pub const fn is_in_between(&self) -> bool {
+        !self.is_first() && !self.last
+    }
+
+    /// Returns `true` if this is the first and the last item, i.e. the
+    /// iterator yields only this one item.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let (_, status) = [27].iter().with_status().next().unwrap();
+    /// assert!(status.is_only());
+    ///
+    /// let (_, status) = [27, 42].iter().with_status().next().unwrap();
+    /// assert!(!status.is_only());
+    /// ```
+    pub const fn is_only(&self) -> bool {
+        self.is_first() && self.last
+    }
+
+    /// Returns the zero-based index of this item, i.e. the number of items the
+    /// iterator yielded before this one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let v: Vec<_> = ['a', 'b', 'c'].iter()
+    ///     .with_status()
+    ///     .map(|(c, status)| (*c, status.index()))
+    ///     .collect();
+    ///
+    /// assert_eq!(v, [('a', 0), ('b', 1), ('c', 2)]);
+    /// ```
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the number of items that come after this item, if known.
+    ///
+    /// For the last item, this is always `Some(0)`. For all other items, the
+    /// number is only known if the underlying iterator reports an exact
+    /// `size_hint`, which is the case for every `ExactSizeIterator`.
+    /// Otherwise, `None` is returned. For iterators which only sometimes
+    /// report an exact `size_hint`, it's unspecified for which items the
+    /// number is known.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let v: Vec<_> = ['a', 'b', 'c'].iter()
+    ///     .with_status()
+    ///     .map(|(c, status)| (*c, status.remaining()))
+    ///     .collect();
+    ///
+    /// assert_eq!(v, [('a', Some(2)), ('b', Some(1)), ('c', Some(0))]);
+    /// ```
+    ///
+    /// If the length of the iterator is unknown, only the last item knows
+    /// that nothing follows it:
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let v: Vec<_> = (1..8)
+    ///     .filter(|i| i % 2 == 0)
+    ///     .with_status()
+    ///     .map(|(i, status)| (i, status.remaining()))
+    ///     .collect();
+    ///
+    /// assert_eq!(v, [(2, None), (4, None), (6, Some(0))]);
+    /// ```
+    pub const fn remaining(&self) -> Option<usize> {
+        self.remaining
+    }
+
+    /// Returns `true` if this item is one of the first `n` items.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let v: Vec<_> = (0..5)
+    ///     .with_status()
+    ///     .filter(|(_, status)| status.is_within_first(2))
+    ///     .map(|(i, _)| i)
+    ///     .collect();
+    ///
+    /// assert_eq!(v, [0, 1]);
+    /// ```
+    pub const fn is_within_first(&self, n: usize) -> bool {
+        self.index < n
+    }
+
+    /// Returns `true` if this item is one of the last `n` items.
+    ///
+    /// This can only be answered if the number of
+    /// [`remaining`][Status::remaining] items is known or this is the last
+    /// item; otherwise `false` is returned. If the length of the iterator is
+    /// unknown, use [`with_lookahead`][IterStatusExt::with_lookahead] to
+    /// make sure this works for a given `n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let v: Vec<_> = (0..5)
+    ///     .with_status()
+    ///     .filter(|(_, status)| status.is_within_last(2))
+    ///     .map(|(i, _)| i)
+    ///     .collect();
+    ///
+    /// assert_eq!(v, [3, 4]);
+    /// ```
+    pub const fn is_within_last(&self, n: usize) -> bool {
+        match self.remaining {
+            Some(remaining) => remaining < n,
+            None => false,
+        }
+    }
+
+    /// Returns `true` if the [`index`][Status::index] of this item is even.
+    /// As the index is zero-based, the first item is even.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let v: Vec<_> = ['a', 'b', 'c'].iter()
+    ///     .with_status()
+    ///     .map(|(c, status)| (*c, status.is_even()))
+    ///     .collect();
+    ///
+    /// assert_eq!(v, [('a', true), ('b', false), ('c', true)]);
+    /// ```
+    pub const fn is_even(&self) -> bool {
+        self.index.is_multiple_of(2)
+    }
+
+    /// Returns `true` if the [`index`][Status::index] of this item is odd.
+    /// As the index is zero-based, the second item is odd.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let v: Vec<_> = ['a', 'b', 'c'].iter()
+    ///     .with_status()
+    ///     .map(|(c, status)| (*c, status.is_odd()))
+    ///     .collect();
+    ///
+    /// assert_eq!(v, [('a', false), ('b', true), ('c', false)]);
+    /// ```
+    pub const fn is_odd(&self) -> bool {
+        !self.is_even()
+    }
+
+    /// Returns `true` if this is the item with the zero-based index `n`, i.e.
+    /// the item that [`Iterator::nth`] would return for `n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let mut s = String::new();
+    /// for (c, status) in ['a', 'b', 'c', 'd'].iter().with_status() {
+    ///     s.push(*c);
+    ///     if status.is_nth(1) {
+    ///         s.push('|');
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(s, "ab|cd");
+    /// ```
+    pub const fn is_nth(&self, n: usize) -> bool {
+        self.index == n
+    }
+
+    /// Returns `true` if this is the `n`-th, `2n`-th, `3n`-th, ... item
+    /// (counting from one), i.e. if `index() + 1` is a multiple of `n`.
+    /// Returns `false` if `n` is 0.
+    ///
+    /// This is useful to do something periodically, e.g. to flush a buffer
+    /// after every 100 records.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let mut s = String::new();
+    /// for (c, status) in "abcdefg".chars().with_status() {
+    ///     s.push(c);
+    ///     if status.is_multiple_of(3) && !status.is_last() {
+    ///         s.push('\n');
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(s, "abc\ndef\ng");
+    /// ```
+    pub const fn is_multiple_of(&self, n: usize) -> bool {
+        n != 0 && self.index % n == n - 1
+    }
+
+    /// Returns the position of this item as [`Position`] enum.
+    ///
+    /// This is useful if you want to `match` on the status and let the
+    /// compiler check that you handled all cases.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::{IterStatusExt, Position};
+    ///
+    /// let v: Vec<_> = (0..4)
+    ///     .with_status()
+    ///     .map(|(_, status)| match status.position() {
+    ///         Position::Only => "only",
+    ///         Position::First => "first",
+    ///         Position::Middle => "middle",
+    ///         Position::Last => "last",
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(v, ["first", "middle", "middle", "last"]);
+    /// ```
+    pub const fn position(&self) -> Position {
+        match (self.is_first(), self.last) {
+            (true, true) => Position::Only,
+            (true, false) => Position::First,
+            (false, false) => Position::Middle,
+            (false, true) => Position::Last,
+        }
+    }
+
+    /// Returns the raw flags of this status as `(is_first, is_last)`, e.g. to
+    /// destructure or `match` on them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let v: Vec<_> = (0..3)
+    ///     .with_status()
+    ///     .map(|(_, status)| match status.into_parts() {
+    ///         (true, _) => "head",
+    ///         (false, false) => "body",
+    ///         (false, true) => "tail",
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(v, ["head", "body", "tail"]);
+    /// ```
+    pub const fn into_parts(self) -> (bool, bool) {
+        (self.is_first(), self.last)
+    }
+
+    /// Returns the name of this item's [`position`][Status::position]:
+    /// `"first"`, `"middle"`, `"last"` or `"only"`. This is also what the
+    /// `Display` impl prints.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let v: Vec<_> = (0..3)
+    ///     .with_status()
+    ///     .map(|(_, status)| status.name())
+    ///     .collect();
+    ///
+    /// assert_eq!(v, ["first", "middle", "last"]);
+    ///
+    /// let (_, status) = [27].iter().with_status().next().unwrap();
+    /// assert_eq!(status.to_string(), "only");
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        match self.position() {
+            Position::Only => "only",
+            Position::First => "first",
+            Position::Middle => "middle",
+            Position::Last => "last",
+        }
+    }
+
+    /// Returns one of the given values depending on the
+    /// [`position`][Status::position] of this item.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::IterStatusExt;
+    ///
+    /// let v: Vec<_> = (0..3)
+    ///     .with_status()
+    ///     .map(|(_, status)| status.select("┏", "┃", "┗", "─"))
+    ///     .collect();
+    ///
+    /// assert_eq!(v, ["┏", "┃", "┗"]);
+    /// ```
+    pub fn select<T>(&self, first: T, middle: T, last: T, only: T) -> T {
+        match self.position() {
+            Position::Only => only,
+            Position::First => first,
+            Position::Middle => middle,
+            Position::Last => last,
+        }
+    }
+
+    /// Returns a compact bit representation of the position of this item:
+    /// [`FIRST_BIT`][Status::FIRST_BIT] is set for the first item and
+    /// [`LAST_BIT`][Status::LAST_BIT] for the last item. All other bits are
+    /// zero.
+    ///
+    /// The index and the number of remaining items are not part of the
+    /// representation. Use [`Position::from_bits`] to decode it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::{IterStatusExt, Status};
+    ///
+    /// let v: Vec<_> = (0..3)
+    ///     .with_status()
+    ///     .map(|(_, status)| status.to_bits())
+    ///     .collect();
+    ///
+    /// assert_eq!(v, [Status::FIRST_BIT, 0, Status::LAST_BIT]);
+    /// ```
+    pub const fn to_bits(&self) -> u8 {
+        self.position().to_bits()
+    }
+}
+
+impl PartialEq for Status {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index && self.last == other.last
+    }
+}
+
+impl Eq for Status {}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+/// The serialized representation of a [`Status`], with explicit `first` and
+/// `last` flags.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SerdeStatus {
+    index: usize,
+    first: bool,
+    last: bool,
+    remaining: Option<usize>,
+}
+
+#[cfg(feature = "serde")]
+impl From<Status> for SerdeStatus {
+    fn from(status: Status) -> Self {
+        Self {
+            index: status.index,
+            first: status.is_first(),
+            last: status.last,
+            remaining: status.remaining,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<SerdeStatus> for Status {
+    type Error = &'static str;
+
+    fn try_from(status: SerdeStatus) -> Result<Self, Self::Error> {
+        if status.first != (status.index == 0) {
+            return Err("`first` must be `true` exactly if `index` is 0");
+        }
+        if status.last != (status.remaining == Some(0)) {
+            return Err("`last` must be `true` exactly if `remaining` is 0");
+        }
+
+        Ok(Status {
+            index: status.index,
+            last: status.last,
+            remaining: status.remaining,
+        })
+    }
+}
+
+/// The position of an item within an iterator, obtained via
+/// [`Status::position`].
+///
+/// In contrast to the predicate methods on [`Status`], each item has exactly
+/// one position, so you can `match` on it exhaustively.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Position {
+    /// The item is the first and the last item (the iterator yields only this
+    /// one item).
+    Only,
+
+    /// The item is the first one, but not the last one.
+    First,
+
+    /// The item is neither the first nor the last one.
+    Middle,
+
+    /// The item is the last one, but not the first one.
+    Last,
+}
+
+impl Position {
+    /// Returns the compact bit representation of this position. See
+    /// [`Status::to_bits`].
+    pub const fn to_bits(self) -> u8 {
+        match self {
+            Position::Only => Status::FIRST_BIT | Status::LAST_BIT,
+            Position::First => Status::FIRST_BIT,
+            Position::Middle => 0,
+            Position::Last => Status::LAST_BIT,
+        }
+    }
+
+    /// Creates a position from its bit representation as returned by
+    /// [`Status::to_bits`] or [`Position::to_bits`]. Returns `None` if any
+    /// bit other than [`Status::FIRST_BIT`] and [`Status::LAST_BIT`] is set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::{IterStatusExt, Position, Status};
+    ///
+    /// let (_, status) = [1, 2].iter().with_status().next().unwrap();
+    /// assert_eq!(Position::from_bits(status.to_bits()), Some(Position::First));
+    ///
+    /// assert_eq!(Position::from_bits(Status::LAST_BIT), Some(Position::Last));
+    /// assert_eq!(Position::from_bits(0b100), None);
+    /// ```
+    pub const fn from_bits(bits: u8) -> Option<Self> {
+        match bits {
+            0b00 => Some(Position::Middle),
+            0b01 => Some(Position::First),
+            0b10 => Some(Position::Last),
+            0b11 => Some(Position::Only),
+            _ => None,
+        }
+    }
+}
+
+/// Requires the `itertools` feature.
+///
+/// # Example
+///
+/// ```
+/// # extern crate itertools;
+/// # extern crate splop;
+/// use splop::IterStatusExt;
+///
+/// let (_, status) = [1, 2].iter().with_status().next().unwrap();
+/// let pos = itertools::Position::from(status);
+/// assert!(pos.is_first && !pos.is_last);
+/// ```
+#[cfg(feature = "itertools")]
+impl From<Status> for itertools::Position {
+    fn from(status: Status) -> Self {
+        Self {
+            is_first: status.is_first(),
+            is_last: status.is_last(),
+        }
+    }
+}
+
+/// Requires the `itertools` feature.
+#[cfg(feature = "itertools")]
+impl From<Position> for itertools::Position {
+    fn from(pos: Position) -> Self {
+        let (is_first, is_last) = match pos {
+            Position::Only => (true, true),
+            Position::First => (true, false),
+            Position::Middle => (false, false),
+            Position::Last => (false, true),
+        };
+
+        Self { is_first, is_last }
+    }
+}
+
+/// Requires the `itertools` feature.
+///
+/// # Example
+///
+/// ```
+/// # extern crate itertools;
+/// # extern crate splop;
+/// use itertools::Itertools;
+/// use splop::Position;
+///
+/// let v: Vec<_> = (0..3)
+///     .with_position()
+///     .map(|(pos, _)| Position::from(pos))
+///     .collect();
+///
+/// assert_eq!(v, [Position::First, Position::Middle, Position::Last]);
+/// ```
+#[cfg(feature = "itertools")]
+impl From<itertools::Position> for Position {
+    fn from(pos: itertools::Position) -> Self {
+        match (pos.is_first, pos.is_last) {
+            (true, true) => Position::Only,
+            (true, false) => Position::First,
+            (false, false) => Position::Middle,
+            (false, true) => Position::Last,
+        }
     }
 }
@@ -0,0 +1,119 @@
+use std::{collections::VecDeque, fmt, iter::FusedIterator};
+
+use crate::{exact_len, Status};
+
+/// Iterator wrapper which looks multiple items ahead. See
+/// [`IterStatusExt::with_lookahead`][crate::IterStatusExt::with_lookahead]
+/// for more information.
+pub struct WithLookahead<I: Iterator> {
+    iter: I,
+
+    /// The items fetched in advance. Unless the underlying iterator is
+    /// exhausted, this always holds `n` items after `next` returned.
+    buffer: VecDeque<I::Item>,
+
+    /// The number of items to look ahead (at least 1).
+    n: usize,
+
+    /// Whether the underlying iterator returned `None` already.
+    done: bool,
+
+    /// The index of the item that will be yielded next.
+    index: usize,
+}
+
+impl<I: Iterator> WithLookahead<I> {
+    pub(crate) fn new(iter: I, n: usize) -> Self {
+        let n = n.max(1);
+        Self {
+            iter,
+            buffer: VecDeque::with_capacity(n + 1),
+            n,
+            done: false,
+            index: 0,
+        }
+    }
+
+    /// Fills the buffer until it holds `len` items or the underlying iterator
+    /// is exhausted.
+    fn fill(&mut self, len: usize) {
+        while !self.done && self.buffer.len() < len {
+            match self.iter.next() {
+                Some(item) => self.buffer.push_back(item),
+                None => self.done = true,
+            }
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for WithLookahead<I> {
+    type Item = (I::Item, Status);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.fill(self.n + 1);
+        let item = self.buffer.pop_front()?;
+
+        let remaining = if self.done {
+            Some(self.buffer.len())
+        } else {
+            exact_len(&self.iter).map(|len| len + self.buffer.len())
+        };
+        let status = Status {
+            index: self.index,
+            last: self.done && self.buffer.is_empty(),
+            remaining,
+        };
+        self.index += 1;
+
+        Some((item, status))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.buffer.len();
+        if self.done {
+            return (buffered, Some(buffered));
+        }
+
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.saturating_add(buffered),
+            upper.and_then(|upper| upper.checked_add(buffered)),
+        )
+    }
+}
+
+impl<I: Iterator> FusedIterator for WithLookahead<I> {}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for WithLookahead<I> {}
+
+impl<I> Clone for WithLookahead<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            buffer: self.buffer.clone(),
+            n: self.n,
+            done: self.done,
+            index: self.index,
+        }
+    }
+}
+
+impl<I> fmt::Debug for WithLookahead<I>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WithLookahead")
+            .field("iter", &self.iter)
+            .field("buffer", &self.buffer)
+            .field("n", &self.n)
+            .field("done", &self.done)
+            .field("index", &self.index)
+            .finish()
+    }
+}
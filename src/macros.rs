@@ -0,0 +1,267 @@
+/// Places a separator between the iterations of a loop or runs an
+/// expression via an existing [`SkipFirst`][crate::SkipFirst].
+///
+/// The loop form declares a hidden `SkipFirst` for the loop: the expression
+/// after `=>` is evaluated at the start of every iteration except the first,
+/// then the block is executed.
+///
+/// ```
+/// use splop::skip_first;
+///
+/// let mut s = String::from("[");
+/// skip_first!(for name in &["banana", "melon", "kiwi"] => s.push_str(", "); {
+///     s.push_str(name);
+/// });
+/// s.push(']');
+///
+/// assert_eq!(s, "[banana, melon, kiwi]");
+/// ```
+///
+/// The other form is a shorthand for
+/// [`SkipFirst::skip_first`][crate::SkipFirst::skip_first] and evaluates to
+/// its result:
+///
+/// ```
+/// use splop::{skip_first, SkipFirst};
+///
+/// let mut comma = SkipFirst::new();
+/// let mut s = String::new();
+/// for name in &["banana", "melon"] {
+///     skip_first!(comma => s.push_str(", "));
+///     s.push_str(name);
+/// }
+///
+/// assert_eq!(s, "banana, melon");
+/// ```
+#[macro_export]
+macro_rules! skip_first {
+    (for $pat:pat in $iter:expr => $sep:expr; $body:block) => {{
+        let mut gate = $crate::SkipFirst::new();
+        for $pat in $iter {
+            if gate.check() {
+                $sep;
+            }
+            $body
+        }
+    }};
+    ($gate:expr => $body:expr) => {
+        $gate.skip_first(|| $body)
+    };
+}
+
+/// Runs the given code only the first time this call site is reached in the
+/// whole process. Evaluates to `Some` with the result the first time and to
+/// `None` afterwards.
+///
+/// This is backed by a hidden `static`
+/// [`AtomicSkipFirst`][crate::AtomicSkipFirst], so it also works across
+/// threads. In contrast to `std::sync::Once`, other threads don't wait until
+/// the code has finished.
+///
+/// # Example
+///
+/// ```
+/// use splop::do_once;
+///
+/// fn setup() -> Option<&'static str> {
+///     do_once!({ "expensive setup" })
+/// }
+///
+/// assert_eq!(setup(), Some("expensive setup"));
+/// assert_eq!(setup(), None);
+/// ```
+#[macro_export]
+macro_rules! do_once {
+    ($($body:tt)*) => {{
+        static ONCE: $crate::AtomicSkipFirst = $crate::AtomicSkipFirst::new();
+        if ONCE.check() {
+            None
+        } else {
+            Some({ $($body)* })
+        }
+    }};
+}
+
+/// Emits a log record with the [`log`](https://docs.rs/log) crate, but only
+/// the first time this call site is reached in the whole process. Requires
+/// the `log` feature.
+///
+/// The arguments are the same as for `log::log!`: a level followed by the
+/// format string and its arguments. If the arguments start with
+/// `key = <expr>,`, the record is emitted once per distinct `Display`
+/// representation of the key instead of once per call site. Keys are only
+/// remembered if the level is enabled, but then forever: memory usage grows
+/// with the number of distinct keys, so don't use unbounded values as keys.
+///
+/// See [`warn_once!`][crate::warn_once] for an example.
+#[cfg(feature = "log")]
+#[macro_export]
+macro_rules! log_once {
+    (key = $key:expr, $lvl:expr, $($arg:tt)+) => {{
+        static SEEN: $crate::__private::SeenKeys = $crate::__private::SeenKeys::new();
+        let lvl = $lvl;
+        if $crate::__private::log_enabled!(lvl)
+            && SEEN.insert(::std::string::ToString::to_string(&$key))
+        {
+            $crate::__private::log!(lvl, $($arg)+);
+        }
+    }};
+    ($lvl:expr, $($arg:tt)+) => {{
+        static ONCE: $crate::AtomicSkipFirst = $crate::AtomicSkipFirst::new();
+        if !ONCE.check() {
+            $crate::__private::log!($lvl, $($arg)+);
+        }
+    }};
+}
+
+/// Like [`log_once!`][crate::log_once] with the level `Warn`. Requires the `log` feature.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "log")]
+/// # fn main() {
+/// use splop::warn_once;
+///
+/// fn old_api(caller: &str) {
+///     // Emitted only once per process.
+///     warn_once!("`old_api` is deprecated");
+///
+///     // Emitted once for every caller.
+///     warn_once!(key = caller, "`old_api` called by {}", caller);
+/// }
+///
+/// old_api("a");
+/// old_api("b");
+/// old_api("a");
+/// # }
+/// # #[cfg(not(feature = "log"))]
+/// # fn main() {}
+/// ```
+#[cfg(feature = "log")]
+#[macro_export]
+macro_rules! warn_once {
+    (key = $key:expr, $($arg:tt)+) => {
+        $crate::log_once!(key = $key, $crate::__private::Level::Warn, $($arg)+)
+    };
+    ($($arg:tt)+) => {
+        $crate::log_once!($crate::__private::Level::Warn, $($arg)+)
+    };
+}
+
+/// Implementation details of the macros. Not public API.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "log")]
+    use std::{collections::BTreeSet, sync::Mutex};
+
+    #[cfg(feature = "log")]
+    pub use log::{log, log_enabled, Level};
+
+    /// The set of keys that `log_once!` already emitted a record for.
+    #[cfg(feature = "log")]
+    pub struct SeenKeys(Mutex<BTreeSet<String>>);
+
+    #[cfg(feature = "log")]
+    impl SeenKeys {
+        pub const fn new() -> Self {
+            SeenKeys(Mutex::new(BTreeSet::new()))
+        }
+
+        /// Returns `true` if the key wasn't seen before.
+        pub fn insert(&self, key: String) -> bool {
+            self.0.lock().unwrap_or_else(|e| e.into_inner()).insert(key)
+        }
+    }
+
+    #[cfg(feature = "log")]
+    impl Default for SeenKeys {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+}
+
+/// A `for` loop with arms that run depending on the status of the item.
+///
+/// The loop body consists of arms `kind => expression`, separated by commas.
+/// For every item, the matching arms are executed in the order they are
+/// written. The following kinds exist, each can be used any number of times:
+///
+/// - `first`: the item is the first one
+/// - `mid`: the item is neither the first nor the last one
+/// - `last`: the item is the last one
+/// - `not_last`: the item is not the last one
+/// - `each`: every item
+///
+/// `break` and `continue` work like in a normal `for` loop.
+///
+/// # Example
+///
+/// ```
+/// use splop::for_status;
+///
+/// fn list(names: &[&str]) -> String {
+///     let mut s = String::new();
+///     for_status! {
+///         for name in names {
+///             first => s.push('['),
+///             each => s.push_str(name),
+///             not_last => s.push_str(", "),
+///             last => s.push(']'),
+///         }
+///     }
+///     s
+/// }
+///
+/// assert_eq!(list(&["peter", "ingrid", "barbara"]), "[peter, ingrid, barbara]");
+/// assert_eq!(list(&["peter"]), "[peter]");
+/// ```
+#[macro_export]
+macro_rules! for_status {
+    // Entry point: collect the tokens of the iterator expression until only
+    // the loop body is left.
+    (for $pat:pat in $($rest:tt)+) => {
+        $crate::for_status!(@iter [$pat] [] $($rest)+)
+    };
+    (@iter [$pat:pat] [$($iter:tt)+] { $($kind:ident => $body:expr),* $(,)? }) => {
+        for ($pat, status) in $crate::IterStatusExt::with_status(
+            ::std::iter::IntoIterator::into_iter($($iter)+)
+        ) {
+            $( $crate::for_status!(@arm status, $kind, $body); )*
+        }
+    };
+    (@iter [$pat:pat] [$($iter:tt)*] $next:tt $($rest:tt)+) => {
+        $crate::for_status!(@iter [$pat] [$($iter)* $next] $($rest)+)
+    };
+    (@arm $status:ident, first, $body:expr) => {
+        if $status.is_first() {
+            $body;
+        }
+    };
+    (@arm $status:ident, mid, $body:expr) => {
+        if $status.is_in_between() {
+            $body;
+        }
+    };
+    (@arm $status:ident, last, $body:expr) => {
+        if $status.is_last() {
+            $body;
+        }
+    };
+    (@arm $status:ident, not_last, $body:expr) => {
+        if !$status.is_last() {
+            $body;
+        }
+    };
+    (@arm $status:ident, each, $body:expr) => {
+        $body;
+    };
+    (@arm $status:ident, $kind:ident, $body:expr) => {
+        compile_error!(concat!(
+            "unknown arm `",
+            stringify!($kind),
+            "` in `for_status!`, expected `first`, `mid`, `last`, `not_last` or `each`",
+        ))
+    };
+}
@@ -0,0 +1,103 @@
+use rayon::iter::{
+    plumbing::{Consumer, ProducerCallback, UnindexedConsumer},
+    IndexedParallelIterator,
+    ParallelIterator,
+};
+
+use crate::Status;
+
+/// Parallel iterator wrapper which pairs each item with its status. See
+/// [`ParIterStatusExt::with_status`] for more information.
+#[derive(Clone, Debug)]
+pub struct ParWithStatus<I> {
+    base: I,
+}
+
+/// Returns the status of the item with the given index in an iterator of
+/// length `len`.
+fn status_at(index: usize, len: usize) -> Status {
+    Status {
+        index,
+        last: index + 1 == len,
+        remaining: Some(len - index - 1),
+    }
+}
+
+impl<I: IndexedParallelIterator> ParallelIterator for ParWithStatus<I> {
+    type Item = (I::Item, Status);
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        let len = self.base.len();
+        self.base.enumerate()
+            .map(move |(index, item)| (item, status_at(index, len)))
+            .drive_unindexed(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.base.len())
+    }
+}
+
+impl<I: IndexedParallelIterator> IndexedParallelIterator for ParWithStatus<I> {
+    fn drive<C>(self, consumer: C) -> C::Result
+    where
+        C: Consumer<Self::Item>,
+    {
+        let len = self.base.len();
+        self.base.enumerate()
+            .map(move |(index, item)| (item, status_at(index, len)))
+            .drive(consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.base.len()
+    }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where
+        CB: ProducerCallback<Self::Item>,
+    {
+        let len = self.base.len();
+        self.base.enumerate()
+            .map(move |(index, item)| (item, status_at(index, len)))
+            .with_producer(callback)
+    }
+}
+
+/// Adds the `with_status` method to all indexed parallel iterators. Requires
+/// the `rayon` feature.
+pub trait ParIterStatusExt: IndexedParallelIterator {
+    /// Creates a parallel iterator that yields the original items paired with
+    /// a [`Status`], just like [`IterStatusExt::with_status`][crate::IterStatusExt::with_status]
+    /// does for iterators.
+    ///
+    /// As the length of indexed parallel iterators is known, the status is
+    /// computed from the index of each item, so it works in parallel just
+    /// fine. [`Status::remaining`] is always known.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate rayon;
+    /// # extern crate splop;
+    /// use rayon::prelude::*;
+    /// use splop::ParIterStatusExt;
+    ///
+    /// let v: Vec<_> = (0..4).into_par_iter()
+    ///     .with_status()
+    ///     .map(|(i, status)| (i, status.is_last()))
+    ///     .collect();
+    ///
+    /// assert_eq!(v, [(0, false), (1, false), (2, false), (3, true)]);
+    /// ```
+    fn with_status(self) -> ParWithStatus<Self>;
+}
+
+impl<I: IndexedParallelIterator> ParIterStatusExt for I {
+    fn with_status(self) -> ParWithStatus<Self> {
+        ParWithStatus { base: self }
+    }
+}
@@ -0,0 +1,174 @@
+use std::{cell::Cell, fmt};
+
+/// Owns a separator value and only hands it out after the first call.
+///
+/// [`get`][Separator::get] returns `None` the first time and `Some` with the
+/// separator on every later call.
+///
+/// # Example
+///
+/// ```
+/// use std::fmt::Write;
+/// use splop::Separator;
+///
+/// let mut s = String::new();
+/// let mut sep = Separator::new(", ");
+/// for name in &["peter", "ingrid", "barbara"] {
+///     write!(s, "{}{}", sep.get().copied().unwrap_or(""), name).unwrap();
+/// }
+///
+/// assert_eq!(s, "peter, ingrid, barbara");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Separator<T> {
+    sep: T,
+    first: bool,
+}
+
+impl<T> Separator<T> {
+    /// Creates a new instance with the given separator.
+    pub const fn new(sep: T) -> Self {
+        Self { sep, first: true }
+    }
+
+    /// Returns `None` the first time this method is called and the separator
+    /// on every later call.
+    pub fn get(&mut self) -> Option<&T> {
+        if self.first {
+            self.first = false;
+            None
+        } else {
+            Some(&self.sep)
+        }
+    }
+
+    /// Resets this instance to its initial state, so that the next call of
+    /// `get` returns `None` again.
+    pub fn reset(&mut self) {
+        self.first = true;
+    }
+
+    /// Returns the separator.
+    pub fn into_inner(self) -> T {
+        self.sep
+    }
+}
+
+/// A separator that formats itself: its `Display` impl prints nothing the
+/// first time and the separator every later time.
+///
+/// The state is stored in a [`Cell`], so formatting advances it even though
+/// it only takes `&self`. Note that this means that every formatting counts,
+/// including e.g. calls of `to_string` in a debugger or logging statement.
+///
+/// # Example
+///
+/// ```
+/// use std::fmt::Write;
+/// use splop::DisplaySep;
+///
+/// let mut s = String::new();
+/// let sep = DisplaySep::new(", ");
+/// for name in &["peter", "ingrid", "barbara"] {
+///     write!(s, "{sep}{name}").unwrap();
+/// }
+///
+/// assert_eq!(s, "peter, ingrid, barbara");
+/// ```
+#[derive(Clone, Debug)]
+pub struct DisplaySep<T> {
+    sep: T,
+    first: Cell<bool>,
+}
+
+impl<T> DisplaySep<T> {
+    /// Creates a new instance with the given separator.
+    pub const fn new(sep: T) -> Self {
+        Self {
+            sep,
+            first: Cell::new(true),
+        }
+    }
+
+    /// Resets this instance to its initial state, so that the next
+    /// formatting prints nothing again.
+    pub fn reset(&self) {
+        self.first.set(true);
+    }
+
+    /// Returns the separator.
+    pub fn into_inner(self) -> T {
+        self.sep
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for DisplaySep<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.first.replace(false) {
+            Ok(())
+        } else {
+            self.sep.fmt(f)
+        }
+    }
+}
+
+/// Returns one value on the first call and another value on every later
+/// call. Created by [`once_then`].
+///
+/// This is the value-returning cousin of [`SkipFirst`][crate::SkipFirst].
+///
+/// # Example
+///
+/// ```
+/// use splop::once_then;
+///
+/// let mut s = String::new();
+/// let mut prefix = once_then("Names: ", ", ");
+/// for name in &["peter", "ingrid"] {
+///     s.push_str(prefix.get());
+///     s.push_str(name);
+/// }
+///
+/// assert_eq!(s, "Names: peter, ingrid");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OnceThen<T> {
+    first: T,
+    rest: T,
+    used: bool,
+}
+
+impl<T> OnceThen<T> {
+    /// Creates a new instance returning `first` on the first call and `rest`
+    /// afterwards.
+    pub const fn new(first: T, rest: T) -> Self {
+        Self {
+            first,
+            rest,
+            used: false,
+        }
+    }
+
+    /// Returns the first value the first time this method is called and the
+    /// other value on every later call.
+    pub fn get(&mut self) -> &T {
+        if self.used {
+            &self.rest
+        } else {
+            self.used = true;
+            &self.first
+        }
+    }
+
+    /// Resets this instance to its initial state, so that the next call of
+    /// `get` returns the first value again.
+    pub fn reset(&mut self) {
+        self.used = false;
+    }
+}
+
+/// Creates a [`OnceThen`] which returns `first` on the first call and `rest`
+/// on every later call.
+pub const fn once_then<T>(first: T, rest: T) -> OnceThen<T> {
+    OnceThen::new(first, rest)
+}
@@ -0,0 +1,155 @@
+use std::{
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use tokio_stream::Stream;
+
+use crate::Status;
+
+pin_project! {
+    /// Stream wrapper which keeps track of the status. See
+    /// [`StreamStatusExt::with_status`] for more information.
+    pub struct StreamWithStatus<S: Stream> {
+        #[pin]
+        stream: S,
+
+        // The item following the one that was yielded last.
+        peeked: Option<S::Item>,
+
+        // The index of the item that will be yielded next.
+        index: usize,
+
+        // Whether the underlying stream is exhausted.
+        done: bool,
+    }
+}
+
+impl<S> fmt::Debug for StreamWithStatus<S>
+where
+    S: Stream + fmt::Debug,
+    S::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StreamWithStatus")
+            .field("stream", &self.stream)
+            .field("peeked", &self.peeked)
+            .field("index", &self.index)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<S: Stream> Stream for StreamWithStatus<S> {
+    type Item = (S::Item, Status);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        // Get the item we want to yield (unless we got it in an earlier call
+        // already).
+        if this.peeked.is_none() {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => *this.peeked = Some(item),
+                Poll::Ready(None) => {
+                    *this.done = true;
+                    return Poll::Ready(None);
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        // Now we need the item after that to know if it's the last one. If
+        // that's not ready yet, we keep our item and try again next time.
+        let next = match this.stream.as_mut().poll_next(cx) {
+            Poll::Ready(next) => next,
+            Poll::Pending => return Poll::Pending,
+        };
+        let last = next.is_none();
+        let item = this.peeked.take().expect("no buffered item");
+        *this.peeked = next;
+        *this.done = last;
+
+        let status = Status {
+            index: *this.index,
+            last,
+            remaining: if last {
+                Some(0)
+            } else {
+                match this.stream.size_hint() {
+                    (lower, Some(upper)) if lower == upper => Some(lower + 1),
+                    _ => None,
+                }
+            },
+        };
+        *this.index += 1;
+
+        Poll::Ready(Some((item, status)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        let peeked = self.peeked.is_some() as usize;
+        let (lower, upper) = self.stream.size_hint();
+        (
+            lower.saturating_add(peeked),
+            upper.and_then(|upper| upper.checked_add(peeked)),
+        )
+    }
+}
+
+/// Adds the `with_status` method to all streams. Requires the `tokio-stream`
+/// feature.
+pub trait StreamStatusExt: Stream + Sized {
+    /// Creates a stream that yields the original items paired with a
+    /// [`Status`], just like [`IterStatusExt::with_status`][crate::IterStatusExt::with_status]
+    /// does for iterators.
+    ///
+    /// An item is only yielded once the following item (or the end of the
+    /// stream) is ready, since only then we know whether it is the last one.
+    ///
+    /// # Example
+    ///
+    /// ```edition2018
+    /// # extern crate splop;
+    /// # extern crate tokio;
+    /// # extern crate tokio_stream;
+    /// use splop::StreamStatusExt;
+    /// use tokio_stream::StreamExt;
+    ///
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() {
+    ///     let mut stream = tokio_stream::iter(vec!["anna", "peter", "bob"])
+    ///         .with_status();
+    ///
+    ///     let mut s = String::new();
+    ///     while let Some((name, status)) = stream.next().await {
+    ///         s += name;
+    ///         if !status.is_last() {
+    ///             s += ", ";
+    ///         }
+    ///     }
+    ///
+    ///     assert_eq!(s, "anna, peter, bob");
+    /// }
+    /// ```
+    fn with_status(self) -> StreamWithStatus<Self>;
+}
+
+impl<S: Stream> StreamStatusExt for S {
+    fn with_status(self) -> StreamWithStatus<Self> {
+        StreamWithStatus {
+            stream: self,
+            peeked: None,
+            index: 0,
+            done: false,
+        }
+    }
+}
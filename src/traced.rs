@@ -0,0 +1,99 @@
+use std::{fmt, iter::FusedIterator};
+
+use crate::{IterStatusExt, Status, WithStatus};
+
+/// Iterator wrapper which keeps track of the status and emits `tracing`
+/// events. See
+/// [`IterStatusExt::with_status_traced`][crate::IterStatusExt::with_status_traced]
+/// for more information.
+pub struct WithStatusTraced<I: Iterator> {
+    iter: WithStatus<I>,
+    span: tracing::Span,
+
+    /// The number of items yielded so far.
+    count: usize,
+    done: bool,
+}
+
+impl<I: Iterator> WithStatusTraced<I> {
+    pub(crate) fn new(iter: I, name: &'static str) -> Self {
+        Self {
+            iter: iter.with_status(),
+            span: tracing::debug_span!("with_status_traced", name),
+            count: 0,
+            done: false,
+        }
+    }
+}
+
+impl<I> Clone for WithStatusTraced<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            span: self.span.clone(),
+            count: self.count,
+            done: self.done,
+        }
+    }
+}
+
+impl<I> fmt::Debug for WithStatusTraced<I>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WithStatusTraced")
+            .field("iter", &self.iter)
+            .field("span", &self.span)
+            .field("count", &self.count)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<I: Iterator> Iterator for WithStatusTraced<I> {
+    type Item = (I::Item, Status);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let _enter = self.span.enter();
+        match self.iter.next() {
+            Some((item, status)) => {
+                if status.is_first() {
+                    tracing::debug!("yielding first item");
+                }
+                self.count += 1;
+                Some((item, status))
+            }
+            None => {
+                // The previously yielded item (if any) was the last one and
+                // its loop iteration is completed now.
+                self.done = true;
+                if self.count > 0 {
+                    tracing::debug!(count = self.count, "completed last item");
+                }
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.iter.size_hint()
+        }
+    }
+}
+
+impl<I: Iterator> FusedIterator for WithStatusTraced<I> {}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for WithStatusTraced<I> {}
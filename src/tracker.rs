@@ -0,0 +1,147 @@
+use crate::Status;
+
+/// Hands out a [`Status`] for every step of a manual loop in which you know
+/// up front whether more steps will follow.
+///
+/// This complements [`SkipFirst`][crate::SkipFirst] for loops that don't use
+/// iterators, e.g. `while` loops, when you also need to know about the last
+/// step.
+///
+/// # Example
+///
+/// ```
+/// use splop::StatusTracker;
+///
+/// let mut tracker = StatusTracker::new();
+/// let mut pending = 3;
+/// let mut s = String::new();
+/// while pending > 0 {
+///     pending -= 1;
+///     let status = tracker.advance(pending > 0);
+///
+///     s.push_str(if status.is_first() { "[" } else { ", " });
+///     s.push_str("x");
+///     if status.is_last() {
+///         s.push(']');
+///     }
+/// }
+///
+/// assert_eq!(s, "[x, x, x]");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StatusTracker {
+    index: usize,
+
+    /// Whether the last step was already handed out.
+    finished: bool,
+}
+
+impl StatusTracker {
+    /// Creates a new tracker. The next step is the first one.
+    pub const fn new() -> Self {
+        Self {
+            index: 0,
+            finished: false,
+        }
+    }
+
+    /// Advances to the next step and returns its status. `has_more` says
+    /// whether more steps follow this one; if it's `false`, the returned
+    /// status is the last one.
+    ///
+    /// The number of [`remaining`][Status::remaining] steps is unknown for
+    /// all but the last step.
+    ///
+    /// After the last step, the tracker must not be advanced again: use
+    /// [`reset`][StatusTracker::reset] or a new tracker for the next loop.
+    /// This is checked in debug builds.
+    pub fn advance(&mut self, has_more: bool) -> Status {
+        debug_assert!(
+            !self.finished,
+            "`StatusTracker::advance` called after the last step",
+        );
+
+        let status = Status::new(self.index, !has_more);
+        self.index += 1;
+        self.finished = !has_more;
+        status
+    }
+
+    /// Resets this tracker to its initial state, so that the next step is the
+    /// first one again.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Returns the number of steps taken so far.
+    pub const fn count(&self) -> usize {
+        self.index
+    }
+}
+
+/// Determines the [`Status`] of items received in a hand-written loop, like
+/// `while let Some(x) = rx.recv()` or a database cursor, where it's unknown
+/// whether an item is the last one until the next one is requested.
+///
+/// Like [`WithStatus`][crate::WithStatus], it holds back one item: every
+/// [`push`][LoopStatus::push] returns the previously pushed item (which is
+/// known not to be the last one). After the loop, [`finish`][LoopStatus::finish]
+/// returns the last item.
+///
+/// # Example
+///
+/// ```
+/// use splop::LoopStatus;
+///
+/// let mut queue = vec!['c', 'b', 'a'];
+/// let mut out = Vec::new();
+///
+/// let mut status = LoopStatus::new();
+/// while let Some(c) = queue.pop() {
+///     if let Some((c, status)) = status.push(c) {
+///         out.push((c, status.is_last()));
+///     }
+/// }
+/// if let Some((c, status)) = status.finish() {
+///     out.push((c, status.is_last()));
+/// }
+///
+/// assert_eq!(out, [('a', false), ('b', false), ('c', true)]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LoopStatus<T> {
+    pending: Option<T>,
+    index: usize,
+}
+
+impl<T> Default for LoopStatus<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> LoopStatus<T> {
+    /// Creates a new instance without any items.
+    pub const fn new() -> Self {
+        Self {
+            pending: None,
+            index: 0,
+        }
+    }
+
+    /// Adds the next item. Returns the previous item and its status (which
+    /// is never the last one), or `None` if this is the first item.
+    pub fn push(&mut self, item: T) -> Option<(T, Status)> {
+        let prev = self.pending.replace(item)?;
+        let status = Status::new(self.index, false);
+        self.index += 1;
+        Some((prev, status))
+    }
+
+    /// Ends the loop and returns the last item and its status, or `None` if
+    /// no item was pushed.
+    pub fn finish(self) -> Option<(T, Status)> {
+        let last = self.pending?;
+        Some((last, Status::new(self.index, true)))
+    }
+}
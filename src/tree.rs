@@ -0,0 +1,225 @@
+use std::{fmt, iter::FusedIterator};
+
+use crate::{IterStatusExt, Position, Status, WithStatus};
+
+/// The prefixes used by [`IterStatusExt::tree_prefixes`] to draw a vertical
+/// guide next to a list of items.
+///
+/// There are presets for Unicode box-drawing characters ([`BOX`][Self::BOX])
+/// and ASCII ([`ASCII`][Self::ASCII]), or you can create your own with
+/// [`new`][Self::new].
+///
+/// # Example
+///
+/// ```
+/// use splop::{IterStatusExt, TreeMarkers};
+///
+/// let markers = TreeMarkers::new("/", "|", "\\", "-");
+/// let prefixes: Vec<_> = (0..3).with_status()
+///     .map(|(_, status)| markers.prefix(status))
+///     .collect();
+///
+/// assert_eq!(prefixes, ["/", "|", "\\"]);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TreeMarkers<'a> {
+    first: &'a str,
+    middle: &'a str,
+    last: &'a str,
+    only: &'a str,
+}
+
+impl<'a> TreeMarkers<'a> {
+    /// Unicode box-drawing characters: `┏`, `┃`, `┗` and `━` for a single
+    /// item.
+    pub const BOX: TreeMarkers<'static> = TreeMarkers::new("┏", "┃", "┗", "━");
+
+    /// ASCII characters: `+`, `|`, `+` and `-` for a single item.
+    pub const ASCII: TreeMarkers<'static> = TreeMarkers::new("+", "|", "+", "-");
+
+    /// Creates custom markers for the first, middle and last item and for an
+    /// item that is the only one.
+    pub const fn new(first: &'a str, middle: &'a str, last: &'a str, only: &'a str) -> Self {
+        Self { first, middle, last, only }
+    }
+
+    /// Returns the marker for an item with the given status.
+    pub const fn prefix(&self, status: Status) -> &'a str {
+        match status.position() {
+            Position::Only => self.only,
+            Position::First => self.first,
+            Position::Middle => self.middle,
+            Position::Last => self.last,
+        }
+    }
+}
+
+impl Default for TreeMarkers<'_> {
+    fn default() -> Self {
+        TreeMarkers::BOX
+    }
+}
+
+/// Iterator adapter that pairs every item with a prefix depending on its
+/// position. See [`IterStatusExt::tree_prefixes`] for more information.
+pub struct TreePrefixes<'a, I: Iterator> {
+    iter: WithStatus<I>,
+    markers: TreeMarkers<'a>,
+}
+
+impl<'a, I: Iterator> TreePrefixes<'a, I> {
+    pub(crate) fn new(iter: I, markers: TreeMarkers<'a>) -> Self {
+        Self {
+            iter: iter.with_status(),
+            markers,
+        }
+    }
+}
+
+impl<I> Clone for TreePrefixes<'_, I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            markers: self.markers,
+        }
+    }
+}
+
+impl<I> fmt::Debug for TreePrefixes<'_, I>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TreePrefixes")
+            .field("iter", &self.iter)
+            .field("markers", &self.markers)
+            .finish()
+    }
+}
+
+impl<'a, I: Iterator> Iterator for TreePrefixes<'a, I> {
+    type Item = (&'a str, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (item, status) = self.iter.next()?;
+        Some((self.markers.prefix(status), item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> DoubleEndedIterator for TreePrefixes<'_, I>
+where
+    I: DoubleEndedIterator + ExactSizeIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (item, status) = self.iter.next_back()?;
+        Some((self.markers.prefix(status), item))
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for TreePrefixes<'_, I> {}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for TreePrefixes<'_, I> {}
+
+/// Keeps track of the position in a nested tree to render prefixes like the
+/// `tree` command does. This is the nested counterpart of [`Status`].
+///
+/// For every level of nesting, the tracker remembers whether the node on
+/// that level is the last child of its parent. Call [`push`][Self::push]
+/// when descending to a child and [`pop`][Self::pop] when leaving it again.
+///
+/// # Example
+///
+/// ```
+/// use splop::{IterStatusExt, TreeTracker};
+///
+/// struct Node(&'static str, Vec<Node>);
+///
+/// fn render(node: &Node, tracker: &mut TreeTracker, out: &mut String) {
+///     for (child, status) in node.1.iter().with_status() {
+///         tracker.push(status.is_last());
+///         out.push_str(&format!("{}{}\n", tracker.prefix(), child.0));
+///         render(child, tracker, out);
+///         tracker.pop();
+///     }
+/// }
+///
+/// let tree = Node(".", vec![
+///     Node("src", vec![Node("lib.rs", vec![]), Node("tree.rs", vec![])]),
+///     Node("Cargo.toml", vec![]),
+/// ]);
+///
+/// let mut out = String::new();
+/// render(&tree, &mut TreeTracker::new(), &mut out);
+/// assert_eq!(out, "\
+/// ├── src
+/// │   ├── lib.rs
+/// │   └── tree.rs
+/// └── Cargo.toml
+/// ");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TreeTracker {
+    last: Vec<bool>,
+}
+
+impl TreeTracker {
+    /// Creates a new tracker at the root (depth 0).
+    pub const fn new() -> Self {
+        Self { last: Vec::new() }
+    }
+
+    /// Descends one level to a node. `is_last` says whether that node is the
+    /// last child of its parent.
+    pub fn push(&mut self, is_last: bool) {
+        self.last.push(is_last);
+    }
+
+    /// Goes up one level again and returns whether the node that was left
+    /// was the last child. Returns `None` if the tracker is at the root.
+    pub fn pop(&mut self) -> Option<bool> {
+        self.last.pop()
+    }
+
+    /// Returns the current depth, i.e. the number of `push` calls without a
+    /// matching `pop`.
+    pub fn depth(&self) -> usize {
+        self.last.len()
+    }
+
+    /// Returns whether the current node is the last child of its parent.
+    /// Returns `false` at the root.
+    pub fn is_last(&self) -> bool {
+        self.last.last().copied().unwrap_or(false)
+    }
+
+    /// Returns whether the node on the given level (starting at 1 for the
+    /// first `push`) is the last child of its parent, or `None` if the
+    /// tracker isn't that deep.
+    pub fn is_last_at(&self, depth: usize) -> Option<bool> {
+        depth.checked_sub(1).and_then(|i| self.last.get(i)).copied()
+    }
+
+    /// Returns the prefix for the current node: `"│   "` or `"    "` for
+    /// every ancestor level (depending on whether more siblings follow on
+    /// that level) and then `"├── "` or `"└── "` for the node itself. At the
+    /// root, the prefix is empty.
+    pub fn prefix(&self) -> String {
+        let mut out = String::new();
+        if let Some((&last, ancestors)) = self.last.split_last() {
+            for &ancestor_last in ancestors {
+                out.push_str(if ancestor_last { "    " } else { "│   " });
+            }
+            out.push_str(if last { "└── " } else { "├── " });
+        }
+        out
+    }
+}
@@ -0,0 +1,305 @@
+use std::{
+    cell::Cell,
+    fmt::{self, Write},
+    io,
+};
+
+use crate::{IterStatusExt, ListFormat, SkipFirst, Status};
+
+/// Adds [`write_separated`][WriteSeparated::write_separated] to all
+/// `io::Write` types.
+pub trait WriteSeparated: io::Write {
+    /// Writes all byte chunks of `iter` with `sep` between every two of them.
+    /// Nothing is written if the iterator is empty.
+    ///
+    /// The chunks are written directly, so no intermediate `String` or
+    /// `Vec` is allocated. As this works with raw bytes, the separator is a
+    /// byte slice and not a [`ListFormat`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use splop::WriteSeparated;
+    ///
+    /// let mut out = Vec::new();
+    /// out.write_separated(&["id", "name", "age"], b",").unwrap();
+    /// out.write_separated(Vec::<&[u8]>::new(), b",").unwrap();
+    ///
+    /// assert_eq!(out, b"id,name,age");
+    /// ```
+    fn write_separated<I>(&mut self, iter: I, sep: &[u8]) -> io::Result<()>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>;
+}
+
+impl<W: io::Write + ?Sized> WriteSeparated for W {
+    fn write_separated<I>(&mut self, iter: I, sep: &[u8]) -> io::Result<()>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        let mut gate = SkipFirst::new();
+        for chunk in iter {
+            if gate.check() {
+                self.write_all(sep)?;
+            }
+            self.write_all(chunk.as_ref())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Adds [`write_separated`][FmtWriteSeparated::write_separated] to all
+/// `fmt::Write` types, including `fmt::Formatter` and `String`.
+pub trait FmtWriteSeparated: fmt::Write {
+    /// Writes the `Display` representations of all items of `iter` with
+    /// `sep` (any [`ListFormat`]) between every two of them. Nothing is
+    /// written if the iterator is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::fmt;
+    /// use splop::FmtWriteSeparated;
+    ///
+    /// struct Path(Vec<&'static str>);
+    ///
+    /// impl fmt::Display for Path {
+    ///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    ///         f.write_separated(&self.0, "::")
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Path(vec!["std", "fmt", "Write"]).to_string(), "std::fmt::Write");
+    /// ```
+    fn write_separated<I, S>(&mut self, iter: I, sep: S) -> fmt::Result
+    where
+        I: IntoIterator,
+        I::Item: fmt::Display,
+        S: ListFormat;
+}
+
+impl<W: fmt::Write> FmtWriteSeparated for W {
+    fn write_separated<I, S>(&mut self, iter: I, sep: S) -> fmt::Result
+    where
+        I: IntoIterator,
+        I::Item: fmt::Display,
+        S: ListFormat,
+    {
+        for (item, status) in iter.into_iter().with_status() {
+            if !status.is_first() {
+                sep.write_separator(self, status)?;
+            }
+            write!(self, "{}", item)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A helper to implement `Display` or `Debug` for list-like types, similar to
+/// `Formatter::debug_list`, but with a custom separator, custom brackets and
+/// per-item formatting.
+///
+/// Configure the builder first, then add entries and finally call
+/// [`finish`][ListBuilder::finish]. By default, entries are separated by
+/// `", "` and there are no brackets.
+///
+/// The separator is a [`ListFormat`]. As a separator may depend on whether
+/// the next entry is the last one, the builder holds back the most recent
+/// entry until the next one is added or `finish` is called. Entries are thus
+/// formatted into a temporary `String` first; of all formatting flags, only
+/// the alternate flag (`#`) is passed on to them.
+///
+/// # Example
+///
+/// ```
+/// use std::fmt;
+/// use splop::{GermanUnd, ListBuilder};
+///
+/// struct Set(Vec<u32>);
+///
+/// impl fmt::Display for Set {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         ListBuilder::new(f)
+///             .sep("; ")
+///             .brackets("{", "}")
+///             .entries(&self.0)
+///             .entry_with(|f| f.write_str("..."))
+///             .finish()
+///     }
+/// }
+///
+/// struct Fruits(Vec<&'static str>);
+///
+/// impl fmt::Display for Fruits {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         ListBuilder::new(f).sep(GermanUnd).entries(&self.0).finish()
+///     }
+/// }
+///
+/// assert_eq!(Set(vec![1, 2]).to_string(), "{1; 2; ...}");
+/// assert_eq!(Set(vec![]).to_string(), "{...}");
+/// let fruits = Fruits(vec!["Äpfel", "Birnen", "Kirschen"]);
+/// assert_eq!(fruits.to_string(), "Äpfel, Birnen und Kirschen");
+/// ```
+pub struct ListBuilder<'a, 'b: 'a, S = &'a str> {
+    f: &'a mut fmt::Formatter<'b>,
+    sep: S,
+    open: &'a str,
+    close: &'a str,
+    pending: Option<String>,
+    index: usize,
+    opened: bool,
+    result: fmt::Result,
+}
+
+impl<'a, 'b: 'a> ListBuilder<'a, 'b> {
+    /// Creates a new builder writing to `f`.
+    pub fn new(f: &'a mut fmt::Formatter<'b>) -> Self {
+        Self {
+            f,
+            sep: ", ",
+            open: "",
+            close: "",
+            pending: None,
+            index: 0,
+            opened: false,
+            result: Ok(()),
+        }
+    }
+}
+
+impl<'a, 'b: 'a, S: ListFormat> ListBuilder<'a, 'b, S> {
+    /// Sets the separator placed between every two entries.
+    pub fn sep<T: ListFormat>(self, sep: T) -> ListBuilder<'a, 'b, T> {
+        ListBuilder {
+            f: self.f,
+            sep,
+            open: self.open,
+            close: self.close,
+            pending: self.pending,
+            index: self.index,
+            opened: self.opened,
+            result: self.result,
+        }
+    }
+
+    /// Sets the strings written before the first and after the last entry.
+    /// They are written even if there are no entries.
+    pub fn brackets(mut self, open: &'a str, close: &'a str) -> Self {
+        self.open = open;
+        self.close = close;
+        self
+    }
+
+    /// Adds an entry which is written by `fmt`. This allows for custom
+    /// formatting of each entry.
+    pub fn entry_with<F>(&mut self, fmt: F) -> &mut Self
+    where
+        F: FnOnce(&mut fmt::Formatter) -> fmt::Result,
+    {
+        self.result = self.result.and_then(|_| {
+            let mut entry = String::new();
+            let fmt = EntryFn(Cell::new(Some(fmt)));
+            if self.f.alternate() {
+                write!(entry, "{:#}", fmt)?;
+            } else {
+                write!(entry, "{}", fmt)?;
+            }
+
+            self.write_pending(false)?;
+            self.pending = Some(entry);
+            Ok(())
+        });
+        self
+    }
+
+    /// Adds an entry formatted with `Display`.
+    pub fn entry(&mut self, entry: &dyn fmt::Display) -> &mut Self {
+        self.entry_with(|f| entry.fmt(f))
+    }
+
+    /// Adds all items of `iter` as entries formatted with `Display`.
+    pub fn entries<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: fmt::Display,
+    {
+        for entry in iter {
+            self.entry_with(|f| fmt::Display::fmt(&entry, f));
+        }
+        self
+    }
+
+    /// Adds all items of `iter` as entries formatted with `Debug`.
+    pub fn debug_entries<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: fmt::Debug,
+    {
+        for entry in iter {
+            self.entry_with(|f| fmt::Debug::fmt(&entry, f));
+        }
+        self
+    }
+
+    /// Writes the last entry and the closing bracket (and the opening one,
+    /// if there were no entries) and returns the result of all write
+    /// operations.
+    pub fn finish(&mut self) -> fmt::Result {
+        self.result = self.result.and_then(|_| {
+            self.write_pending(true)?;
+            self.f.write_str(self.close)
+        });
+        self.result
+    }
+
+    /// Writes the opening bracket if that hasn't happened yet, followed by
+    /// the held back entry (if any) and the separator in front of it.
+    fn write_pending(&mut self, last: bool) -> fmt::Result {
+        if !self.opened {
+            self.opened = true;
+            self.f.write_str(self.open)?;
+        }
+
+        if let Some(entry) = self.pending.take() {
+            if self.index > 0 {
+                self.sep.write_separator(self.f, Status::new(self.index, last))?;
+            }
+            self.index += 1;
+            self.f.write_str(&entry)?;
+        }
+        Ok(())
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for ListBuilder<'_, '_, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ListBuilder")
+            .field("sep", &self.sep)
+            .field("open", &self.open)
+            .field("close", &self.close)
+            .field("pending", &self.pending)
+            .field("opened", &self.opened)
+            .field("result", &self.result)
+            .finish()
+    }
+}
+
+/// Formats an entry of a `ListBuilder` with the given closure.
+struct EntryFn<F>(Cell<Option<F>>);
+
+impl<F> fmt::Display for EntryFn<F>
+where
+    F: FnOnce(&mut fmt::Formatter) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0.take() {
+            Some(fmt) => fmt(f),
+            None => Ok(()),
+        }
+    }
+}
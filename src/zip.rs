@@ -0,0 +1,146 @@
+use std::{fmt, iter::FusedIterator};
+
+use crate::{IterStatusExt, Status, WithStatus};
+
+/// Iterator adapter which zips two iterators and yields the status of each
+/// pair. See
+/// [`IterStatusExt::zip_with_status`][crate::IterStatusExt::zip_with_status]
+/// for more information.
+pub struct ZipWithStatus<A: Iterator, B: Iterator> {
+    a: WithStatus<A>,
+    b: WithStatus<B>,
+
+    /// The index of the pair that will be yielded next.
+    index: usize,
+}
+
+impl<A: Iterator, B: Iterator> ZipWithStatus<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        Self {
+            a: a.with_status(),
+            b: b.with_status(),
+            index: 0,
+        }
+    }
+}
+
+impl<A, B> Clone for ZipWithStatus<A, B>
+where
+    A: Iterator + Clone,
+    A::Item: Clone,
+    B: Iterator + Clone,
+    B::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            a: self.a.clone(),
+            b: self.b.clone(),
+            index: self.index,
+        }
+    }
+}
+
+impl<A, B> fmt::Debug for ZipWithStatus<A, B>
+where
+    A: Iterator + fmt::Debug,
+    A::Item: fmt::Debug,
+    B: Iterator + fmt::Debug,
+    B::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ZipWithStatus")
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+impl<A: Iterator, B: Iterator> Iterator for ZipWithStatus<A, B> {
+    type Item = ((A::Item, B::Item), ZipStatus);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (a, a_status) = self.a.next()?;
+        let (b, b_status) = self.b.next()?;
+
+        let exhausted = match (a_status.is_last(), b_status.is_last()) {
+            (false, false) => None,
+            (true, false) => Some(Exhausted::Left),
+            (false, true) => Some(Exhausted::Right),
+            (true, true) => Some(Exhausted::Both),
+        };
+        let remaining = match (exhausted, a_status.remaining(), b_status.remaining()) {
+            (Some(_), _, _) => Some(0),
+            (None, Some(a), Some(b)) => Some(a.min(b)),
+            _ => None,
+        };
+
+        let status = ZipStatus {
+            status: Status {
+                index: self.index,
+                last: exhausted.is_some(),
+                remaining,
+            },
+            exhausted,
+        };
+        self.index += 1;
+
+        Some(((a, b), status))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, None) => a,
+            (None, b) => b,
+        };
+
+        (a_lower.min(b_lower), upper)
+    }
+}
+
+impl<A: FusedIterator, B: FusedIterator> FusedIterator for ZipWithStatus<A, B> {}
+
+impl<A, B> ExactSizeIterator for ZipWithStatus<A, B>
+where
+    A: ExactSizeIterator,
+    B: ExactSizeIterator,
+{}
+
+/// The status of a pair yielded by [`ZipWithStatus`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ZipStatus {
+    status: Status,
+    exhausted: Option<Exhausted>,
+}
+
+impl ZipStatus {
+    /// Returns the status of the pair within the zipped iterator.
+    pub const fn status(&self) -> Status {
+        self.status
+    }
+
+    /// Returns which of the two iterators is exhausted after this pair. This
+    /// is only `Some` for the last pair.
+    pub const fn exhausted(&self) -> Option<Exhausted> {
+        self.exhausted
+    }
+}
+
+/// Which of two zipped iterators ran out of items first, obtained via
+/// [`ZipStatus::exhausted`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Exhausted {
+    /// The left (`self`) iterator is exhausted, but the right one still has
+    /// items left.
+    Left,
+
+    /// The right (`other`) iterator is exhausted, but the left one still has
+    /// items left.
+    Right,
+
+    /// Both iterators are exhausted, i.e. they had the same length.
+    Both,
+}